@@ -0,0 +1,142 @@
+//! `cargo bench -p aoc` benchmarks parse, part 1 and part 2 of every day on
+//! its real input. Alternative implementations of a day (`day_01_opt`,
+//! `day_07`) are registered in the same group so they show up side by side.
+//!
+//! After the run a JSON summary is written to
+//! `target/criterion/aoc-report.json` (or `$AOC_BENCH_REPORT`).
+
+use std::collections::HashSet;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use aoc::days::{self, SolutionFn};
+use criterion::{BatchSize, BenchmarkId, Criterion};
+use serde_json::{json, Value};
+
+fn implementations(day: &days::Day) -> Vec<(&'static str, SolutionFn)> {
+    let mut implementations: Vec<(&'static str, SolutionFn)> = vec![(day.crate_name, day.solution)];
+
+    match day.day {
+        1 => implementations.push(("day_01_opt", || Box::new(day_01_opt::AOC_2022_01::new()))),
+        7 => implementations.push(("day_07", || Box::new(day_07::AOC_2022_07::new()))),
+        _ => {}
+    }
+
+    implementations
+}
+
+fn bench_days(c: &mut Criterion) -> Vec<String> {
+    let mut ids = vec![];
+
+    for day in days::all() {
+        let Ok(input) = aoc_input::read_file(day.input) else {
+            eprintln!("skipping day {}: no input at {}", day.day, day.input);
+            continue;
+        };
+
+        let group_name = format!("day_{:02}", day.day);
+        let mut group = c.benchmark_group(&group_name);
+        group.sample_size(10).warm_up_time(Duration::from_millis(500));
+
+        for (name, solution) in implementations(&day) {
+            group.bench_function(BenchmarkId::new("parse", name), |b| {
+                b.iter_batched(solution, |mut s| s.load(&input), BatchSize::SmallInput)
+            });
+            ids.push(format!("{}/parse/{}", group_name, name));
+
+            let mut loaded = solution();
+            if let Err(err) = loaded.load(&input) {
+                eprintln!("skipping {}: {}", name, err);
+                continue;
+            }
+
+            for part in 1..=2 {
+                if !loaded.solved(part) {
+                    continue;
+                }
+
+                let step = format!("part{}", part);
+                group.bench_function(BenchmarkId::new(&step, name), |b| b.iter(|| loaded.answer(part)));
+                ids.push(format!("{}/{}/{}", group_name, step, name));
+            }
+        }
+
+        group.finish();
+    }
+
+    ids
+}
+
+fn criterion_dir() -> PathBuf {
+    if let Some(home) = env::var_os("CRITERION_HOME") {
+        return PathBuf::from(home);
+    }
+
+    match env::var_os("CARGO_TARGET_DIR") {
+        Some(target) => PathBuf::from(target).join("criterion"),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("../target/criterion"),
+    }
+}
+
+fn read_json(path: &Path) -> Option<Value> {
+    serde_json::from_str(&fs::read_to_string(path).ok()?).ok()
+}
+
+/// Every `new/benchmark.json` criterion left behind, keyed by its full id.
+fn collect_results(dir: &Path, ids: &HashSet<String>, results: &mut Vec<Value>) {
+    let Ok(entries) = fs::read_dir(dir) else { return };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+
+        let (Some(benchmark), Some(estimates)) =
+            (read_json(&path.join("new/benchmark.json")), read_json(&path.join("new/estimates.json")))
+        else {
+            collect_results(&path, ids, results);
+            continue;
+        };
+
+        let Some(id) = benchmark["full_id"].as_str().filter(|id| ids.contains(*id)) else { continue };
+        let mut parts = id.split('/');
+
+        results.push(json!({
+            "id": id,
+            "day": parts.next(),
+            "step": parts.next(),
+            "implementation": parts.next(),
+            "mean_ns": estimates["mean"]["point_estimate"],
+            "median_ns": estimates["median"]["point_estimate"],
+            "std_dev_ns": estimates["std_dev"]["point_estimate"],
+        }));
+    }
+}
+
+fn write_report(ids: &[String]) {
+    let ids: HashSet<String> = ids.iter().cloned().collect();
+    let mut results = vec![];
+    collect_results(&criterion_dir(), &ids, &mut results);
+    results.sort_by(|a, b| a["id"].as_str().cmp(&b["id"].as_str()));
+
+    let path = env::var_os("AOC_BENCH_REPORT")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| criterion_dir().join("aoc-report.json"));
+    let report = json!({ "benchmarks": results });
+
+    match fs::write(&path, serde_json::to_string_pretty(&report).unwrap()) {
+        Ok(()) => println!("benchmark report written to {}", path.display()),
+        Err(e) => eprintln!("could not write benchmark report to {}: {}", path.display(), e),
+    }
+}
+
+fn main() {
+    let mut criterion = Criterion::default().configure_from_args();
+    let ids = bench_days(&mut criterion);
+    criterion.final_summary();
+
+    write_report(&ids);
+}
@@ -0,0 +1,134 @@
+use aoc_solution::AnySolution;
+
+pub type SolutionFn = fn() -> Box<dyn AnySolution>;
+
+pub struct Day {
+    pub day: u8,
+    pub crate_name: &'static str,
+    pub input: &'static str,
+    pub answers: &'static str,
+    pub solution: SolutionFn,
+}
+
+macro_rules! asset_path {
+    ($dir:literal, $file:literal) => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../", $dir, "/assets/", $file)
+    };
+}
+
+pub fn all() -> Vec<Day> {
+    vec![
+        Day {
+            day: 1,
+            crate_name: "day_01",
+            input: asset_path!("day_01", "input.txt"),
+            answers: asset_path!("day_01", "answers.toml"),
+            solution: || Box::new(day_01::AOC_2022_01::new()),
+        },
+        Day {
+            day: 2,
+            crate_name: "day_02",
+            input: asset_path!("day_02", "input.txt"),
+            answers: asset_path!("day_02", "answers.toml"),
+            solution: || Box::new(day_02::AOC_2022_02::new()),
+        },
+        Day {
+            day: 3,
+            crate_name: "day_03",
+            input: asset_path!("day_03", "input.txt"),
+            answers: asset_path!("day_03", "answers.toml"),
+            solution: || Box::new(day_03::AOC_2022_03::new()),
+        },
+        Day {
+            day: 4,
+            crate_name: "day_04",
+            input: asset_path!("day_04", "input.txt"),
+            answers: asset_path!("day_04", "answers.toml"),
+            solution: || Box::new(day_04::AOC_2022_04::new()),
+        },
+        Day {
+            day: 5,
+            crate_name: "day_05",
+            input: asset_path!("day_05", "input.txt"),
+            answers: asset_path!("day_05", "answers.toml"),
+            solution: || Box::new(day_05::AOC_2022_05::new()),
+        },
+        Day {
+            day: 6,
+            crate_name: "day_06",
+            input: asset_path!("day_06", "input.txt"),
+            answers: asset_path!("day_06", "answers.toml"),
+            solution: || Box::new(day_06::AOC_2022_06::new()),
+        },
+        Day {
+            day: 7,
+            crate_name: "day_07_opt",
+            input: asset_path!("day_07_opt", "input.txt"),
+            answers: asset_path!("day_07_opt", "answers.toml"),
+            solution: || Box::new(day_07_opt::AOC_2022_07::new()),
+        },
+        Day {
+            day: 8,
+            crate_name: "day_08",
+            input: asset_path!("day_08", "input.txt"),
+            answers: asset_path!("day_08", "answers.toml"),
+            solution: || Box::new(day_08::AOC_2022_08::new()),
+        },
+        Day {
+            day: 9,
+            crate_name: "day_09",
+            input: asset_path!("day_09", "input.txt"),
+            answers: asset_path!("day_09", "answers.toml"),
+            solution: || Box::new(day_09::AOC_2022_09::new()),
+        },
+        Day {
+            day: 10,
+            crate_name: "day_10",
+            input: asset_path!("day_10", "input.txt"),
+            answers: asset_path!("day_10", "answers.toml"),
+            solution: || Box::new(day_10::AOC_2022_10::new()),
+        },
+        Day {
+            day: 11,
+            crate_name: "day_11",
+            input: asset_path!("day_11", "input.txt"),
+            answers: asset_path!("day_11", "answers.toml"),
+            solution: || Box::new(day_11::AOC_2022_11::new()),
+        },
+        Day {
+            day: 12,
+            crate_name: "day_12",
+            input: asset_path!("day_12", "input.txt"),
+            answers: asset_path!("day_12", "answers.toml"),
+            solution: || Box::new(day_12::AOC_2022_12::new()),
+        },
+        Day {
+            day: 13,
+            crate_name: "day_13",
+            input: asset_path!("day_13", "input.txt"),
+            answers: asset_path!("day_13", "answers.toml"),
+            solution: || Box::new(day_13::AOC_2022_13::new()),
+        },
+        Day {
+            day: 14,
+            crate_name: "day_14",
+            input: asset_path!("day_14", "input.txt"),
+            answers: asset_path!("day_14", "answers.toml"),
+            solution: || Box::new(day_14::AOC_2022_14::new()),
+        },
+        Day {
+            day: 15,
+            crate_name: "day_15_alt",
+            input: asset_path!("day_15", "input.txt"),
+            answers: asset_path!("day_15", "answers.toml"),
+            solution: || Box::new(day_15_alt::AOC_2022_15::new()),
+        },
+        Day {
+            day: 16,
+            crate_name: "day_16",
+            input: asset_path!("day_16", "input.txt"),
+            answers: asset_path!("day_16", "answers.toml"),
+            solution: || Box::new(day_16::AOC_2022_16::new()),
+        },
+    ]
+}
@@ -0,0 +1,253 @@
+use std::error::Error;
+
+use aoc_input::Source;
+use std::time::{Duration, Instant};
+
+pub mod days;
+pub mod verify;
+
+pub static USAGE: &str = "usage: aoc run [--day <1-25>] [--part <1|2>] [--input <path|->]
+       aoc verify [--day <1-25>] [--part <1|2>] [--record]";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run,
+    Verify,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    pub command: Command,
+    pub day: Option<u8>,
+    pub part: Option<u8>,
+    pub input: Option<String>,
+    pub record: bool,
+}
+
+impl Config {
+    pub fn build(args: &[String]) -> Result<Config, String> {
+        let mut args = args.iter().skip(1);
+
+        let command = match args.next().map(|s| s.as_str()) {
+            Some("run") => Command::Run,
+            Some("verify") => Command::Verify,
+            Some(other) => return Err(format!("unknown command '{}'", other)),
+            None => return Err("no command given".to_string()),
+        };
+
+        let mut config = Config { command, day: None, part: None, input: None, record: false };
+
+        while let Some(flag) = args.next() {
+            if flag == "--record" {
+                config.record = true;
+                continue;
+            }
+
+            let value = args
+                .next()
+                .ok_or_else(|| format!("missing value for {}", flag))?;
+
+            match flag.as_str() {
+                "--day" | "-d" => config.day = Some(parse_number(flag, value, 1..=25)?),
+                "--part" | "-p" => config.part = Some(parse_number(flag, value, 1..=2)?),
+                "--input" | "-i" => config.input = Some(value.to_string()),
+                _ => return Err(format!("unknown flag '{}'", flag)),
+            }
+        }
+
+        if config.input.is_some() && config.day.is_none() {
+            return Err("--input can only be used together with --day".to_string());
+        }
+
+        match config.command {
+            Command::Run if config.record => return Err("--record can only be used with verify".to_string()),
+            Command::Verify if config.input.is_some() => {
+                return Err("verify always uses the recorded inputs, --input is not supported".to_string())
+            }
+            _ => {}
+        }
+
+        Ok(config)
+    }
+}
+
+fn parse_number(flag: &str, value: &str, range: std::ops::RangeInclusive<u8>) -> Result<u8, String> {
+    match value.parse::<u8>() {
+        Ok(n) if range.contains(&n) => Ok(n),
+        _ => Err(format!(
+            "{} expects a number between {} and {}, got '{}'",
+            flag,
+            range.start(),
+            range.end(),
+            value
+        )),
+    }
+}
+
+pub struct Row {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub elapsed: Option<Duration>,
+}
+
+pub fn selected_days(config: &Config) -> Result<Vec<days::Day>, Box<dyn Error>> {
+    let selected: Vec<days::Day> = days::all()
+        .into_iter()
+        .filter(|d| config.day.is_none_or(|day| d.day == day))
+        .collect();
+
+    if selected.is_empty() {
+        return Err(format!("no solution registered for day {}", config.day.unwrap_or(0)).into());
+    }
+
+    Ok(selected)
+}
+
+pub fn selected_parts(config: &Config) -> Vec<u8> {
+    (1..=2).filter(|p| config.part.is_none_or(|part| part == *p)).collect()
+}
+
+pub fn run(config: &Config) -> Result<(), Box<dyn Error>> {
+    let selected = selected_days(config)?;
+    let mut rows: Vec<Row> = vec![];
+
+    for day in selected.iter() {
+        let input_path = config.input.as_deref().unwrap_or(day.input);
+        let parts = selected_parts(config);
+
+        let mut solution = (day.solution)();
+        let loaded = aoc_input::load(Source::from_arg(input_path))
+            .map_err(|err| err.to_string())
+            .and_then(|input| solution.load(&input).map_err(|err| format!("{}: {}", input_path, err)));
+
+        if let Err(err) = loaded {
+            for part in parts {
+                rows.push(Row { day: day.day, part, answer: err.clone(), elapsed: None });
+            }
+            continue;
+        }
+
+        for part in parts {
+            let started = Instant::now();
+            let row = match solution.answer(part) {
+                Some(answer) => Row { day: day.day, part, answer, elapsed: Some(started.elapsed()) },
+                None => Row { day: day.day, part, answer: "not implemented".to_string(), elapsed: None },
+            };
+            rows.push(row);
+        }
+    }
+
+    println!();
+    println!("{}", render_table(&rows));
+
+    Ok(())
+}
+
+pub fn render_table(rows: &[Row]) -> String {
+    let answer_width = rows
+        .iter()
+        .flat_map(|r| r.answer.lines())
+        .map(|l| l.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+
+    let mut lines: Vec<String> = vec![
+        format!("{:>3}  {:>4}  {:<answer_width$}  {:>12}", "Day", "Part", "Answer", "Time"),
+        format!("{}  {}  {}  {}", "-".repeat(3), "-".repeat(4), "-".repeat(answer_width), "-".repeat(12)),
+    ];
+
+    for row in rows {
+        let elapsed = match row.elapsed {
+            Some(e) => format!("{:.2?}", e),
+            None => "-".to_string(),
+        };
+        let mut answer_lines = row.answer.lines();
+        let first = answer_lines.next().unwrap_or("");
+
+        lines.push(format!("{:>3}  {:>4}  {:<answer_width$}  {:>12}", row.day, row.part, first, elapsed));
+        for more in answer_lines {
+            lines.push(format!("{:>3}  {:>4}  {}", "", "", more));
+        }
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split(' ').map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn config_build_01() {
+        let config = Config::build(&args("aoc run --day 13 --part 2 --input foo.txt")).unwrap();
+
+        assert_eq!(
+            config,
+            Config {
+                command: Command::Run,
+                day: Some(13),
+                part: Some(2),
+                input: Some("foo.txt".to_string()),
+                record: false
+            }
+        );
+    }
+
+    #[test]
+    fn config_build_02() {
+        let config = Config::build(&args("aoc run")).unwrap();
+
+        assert_eq!(config, Config { command: Command::Run, day: None, part: None, input: None, record: false });
+    }
+
+    #[test]
+    fn config_build_03() {
+        let config = Config::build(&args("aoc verify --record --day 4")).unwrap();
+
+        assert_eq!(config, Config { command: Command::Verify, day: Some(4), part: None, input: None, record: true });
+    }
+
+    #[test]
+    fn config_build_errors() {
+        assert!(Config::build(&args("aoc")).is_err());
+        assert!(Config::build(&args("aoc fly")).is_err());
+        assert!(Config::build(&args("aoc run --part 3")).is_err());
+        assert!(Config::build(&args("aoc run --day")).is_err());
+        assert!(Config::build(&args("aoc run --input foo.txt")).is_err());
+        assert!(Config::build(&args("aoc run --record")).is_err());
+        assert!(Config::build(&args("aoc verify --day 1 --input foo.txt")).is_err());
+    }
+
+    #[test]
+    fn render_table_01() {
+        let rows = vec![
+            Row { day: 1, part: 1, answer: "71506".to_string(), elapsed: None },
+            Row { day: 10, part: 2, answer: "#..\n.#.".to_string(), elapsed: None },
+        ];
+
+        assert_eq!(
+            render_table(&rows),
+            [
+                "Day  Part  Answer          Time",
+                "---  ----  ------  ------------",
+                "  1     1  71506              -",
+                " 10     2  #..                -",
+                "           .#.",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn all_days_registered() {
+        let registered = days::all().iter().map(|d| d.day).collect::<Vec<u8>>();
+
+        assert_eq!(registered, (1..=16).collect::<Vec<u8>>());
+    }
+}
@@ -0,0 +1,26 @@
+use aoc::{Command, Config, USAGE};
+use std::env;
+use std::process;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let config = Config::build(&args).unwrap_or_else(|err| {
+        eprintln!("{err}");
+        eprintln!("{USAGE}");
+        process::exit(2);
+    });
+
+    let result = match config.command {
+        Command::Run => aoc::run(&config).map(|_| true),
+        Command::Verify => aoc::verify::verify(&config),
+    };
+
+    match result {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(e) => {
+            eprintln!("App error: {e}");
+            process::exit(1);
+        }
+    }
+}
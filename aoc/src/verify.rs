@@ -0,0 +1,206 @@
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use aoc_input::Source;
+use toml::{Table, Value};
+
+use crate::Config;
+
+/// Outcome of checking one part against `assets/answers.toml`.
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String },
+    Missing,
+    Recorded,
+    Unsolved,
+    Error(String),
+}
+
+impl Status {
+    pub fn is_ok(&self) -> bool {
+        matches!(self, Status::Pass | Status::Recorded | Status::Unsolved)
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail { .. } => "FAIL",
+            Status::Missing => "missing",
+            Status::Recorded => "recorded",
+            Status::Unsolved => "skipped",
+            Status::Error(_) => "error",
+        }
+    }
+}
+
+pub struct Outcome {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub status: Status,
+}
+
+fn key(part: u8) -> String {
+    format!("part{}", part)
+}
+
+/// Answers are stored as strings (`part1 = "71506"`) so numbers and
+/// multi-line screens like day 10 are compared the same way.
+pub fn load_answers(path: &Path) -> Result<Table, Box<dyn Error>> {
+    if !path.exists() {
+        return Ok(Table::new());
+    }
+
+    let content = fs::read_to_string(path)?;
+    content
+        .parse::<Table>()
+        .map_err(|e| format!("could not parse {}: {}", path.display(), e).into())
+}
+
+pub fn save_answers(path: &Path, answers: &Table) -> Result<(), Box<dyn Error>> {
+    fs::write(path, toml::to_string(answers)?)?;
+    Ok(())
+}
+
+pub fn check(answers: &Table, part: u8, answer: &str) -> Status {
+    match answers.get(&key(part)) {
+        None => Status::Missing,
+        Some(Value::String(expected)) if expected == answer => Status::Pass,
+        Some(Value::String(expected)) => Status::Fail { expected: expected.to_string() },
+        Some(other) if other.to_string() == answer => Status::Pass,
+        Some(other) => Status::Fail { expected: other.to_string() },
+    }
+}
+
+/// Runs the selected parts against their recorded answers and prints a
+/// report. Returns `false` if anything failed or is still missing.
+pub fn verify(config: &Config) -> Result<bool, Box<dyn Error>> {
+    let mut outcomes: Vec<Outcome> = vec![];
+
+    for day in crate::selected_days(config)?.iter() {
+        let parts = crate::selected_parts(config);
+        let answers_path = Path::new(day.answers);
+        let mut answers = load_answers(answers_path)?;
+        let mut changed = false;
+
+        let mut solution = (day.solution)();
+        let loaded = aoc_input::load(Source::Path(Path::new(day.input)))
+            .map_err(|err| err.to_string())
+            .and_then(|input| solution.load(&input).map_err(|err| format!("{}: {}", day.input, err)));
+
+        if let Err(err) = loaded {
+            for part in parts {
+                outcomes.push(Outcome { day: day.day, part, answer: String::new(), status: Status::Error(err.clone()) });
+            }
+            continue;
+        }
+
+        for part in parts {
+            let Some(answer) = solution.answer(part) else {
+                outcomes.push(Outcome { day: day.day, part, answer: String::new(), status: Status::Unsolved });
+                continue;
+            };
+
+            let mut status = check(&answers, part, &answer);
+            if status == Status::Missing && config.record {
+                answers.insert(key(part), Value::String(answer.clone()));
+                changed = true;
+                status = Status::Recorded;
+            }
+
+            outcomes.push(Outcome { day: day.day, part, answer, status });
+        }
+
+        if changed {
+            save_answers(answers_path, &answers)?;
+        }
+    }
+
+    println!();
+    println!("{}", render_report(&outcomes));
+
+    Ok(outcomes.iter().all(|o| o.status.is_ok()))
+}
+
+pub fn render_report(outcomes: &[Outcome]) -> String {
+    let mut lines: Vec<String> = vec![
+        format!("{:>3}  {:>4}  {:<8}  {}", "Day", "Part", "Status", "Answer"),
+        format!("{}  {}  {}  {}", "-".repeat(3), "-".repeat(4), "-".repeat(8), "-".repeat(6)),
+    ];
+
+    for outcome in outcomes {
+        let detail = match &outcome.status {
+            Status::Fail { expected } => format!("{}\nexpected:\n{}", outcome.answer, expected),
+            Status::Error(err) => err.to_string(),
+            _ => outcome.answer.clone(),
+        };
+        let mut detail_lines = detail.lines();
+
+        lines.push(format!(
+            "{:>3}  {:>4}  {:<8}  {}",
+            outcome.day,
+            outcome.part,
+            outcome.status.label(),
+            detail_lines.next().unwrap_or("")
+        ));
+        for more in detail_lines {
+            lines.push(format!("{:>3}  {:>4}  {:<8}  {}", "", "", "", more));
+        }
+    }
+
+    let failed = outcomes.iter().filter(|o| !o.status.is_ok()).count();
+    lines.push(String::new());
+    lines.push(format!("{} checked, {} not ok", outcomes.len(), failed));
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_01() {
+        let answers = "part1 = \"71506\"\npart2 = 209603\n".parse::<Table>().unwrap();
+
+        assert_eq!(check(&answers, 1, "71506"), Status::Pass);
+        assert_eq!(check(&answers, 1, "1"), Status::Fail { expected: "71506".to_string() });
+        assert_eq!(check(&answers, 2, "209603"), Status::Pass);
+        assert_eq!(check(&Table::new(), 1, "71506"), Status::Missing);
+    }
+
+    #[test]
+    fn answers_round_trip() {
+        let mut answers = Table::new();
+        answers.insert(key(2), Value::String("#..\n.#.".to_string()));
+
+        let parsed = toml::to_string(&answers).unwrap().parse::<Table>().unwrap();
+
+        assert_eq!(check(&parsed, 2, "#..\n.#."), Status::Pass);
+    }
+
+    #[test]
+    fn render_report_01() {
+        let outcomes = vec![
+            Outcome { day: 1, part: 1, answer: "71506".to_string(), status: Status::Pass },
+            Outcome { day: 1, part: 2, answer: "1".to_string(), status: Status::Fail { expected: "2".to_string() } },
+        ];
+
+        assert_eq!(
+            render_report(&outcomes),
+            [
+                "Day  Part  Status    Answer",
+                "---  ----  --------  ------",
+                "  1     1  pass      71506",
+                "  1     2  FAIL      1",
+                "                     expected:",
+                "                     2",
+                "",
+                "2 checked, 1 not ok",
+            ]
+            .join("\n")
+        );
+    }
+}
@@ -0,0 +1,298 @@
+use std::iter;
+use std::ops::{Index, IndexMut};
+
+use aoc_input::ParseError;
+
+/// `(x, y)`, with `y` growing downwards like the lines of the puzzle input.
+pub type Point = (isize, isize);
+
+pub const UP: Point = (0, -1);
+pub const RIGHT: Point = (1, 0);
+pub const DOWN: Point = (0, 1);
+pub const LEFT: Point = (-1, 0);
+
+pub const DIRECTIONS_4: [Point; 4] = [UP, RIGHT, DOWN, LEFT];
+pub const DIRECTIONS_8: [Point; 8] = [UP, (1, -1), RIGHT, (1, 1), DOWN, (-1, 1), LEFT, (-1, -1)];
+
+pub fn step((x, y): Point, (dx, dy): Point) -> Point {
+    (x + dx, y + dy)
+}
+
+/// A dense, rectangular grid stored row by row. Its top left cell is at
+/// `origin`, which is `(0, 0)` unless the puzzle's coordinates start
+/// somewhere else (like day 14's sand around `x=500`). Cells outside the
+/// grid don't exist, [`Grid::grow_to_include`] makes room for them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+    origin: Point,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_vec(width, height, vec![fill; width * height])
+    }
+
+    /// `cells` are the rows, top to bottom, one after the other.
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "a {}x{} grid needs {} cells", width, height, width * height);
+        Self { cells, width, height, origin: (0, 0) }
+    }
+
+    pub fn with_origin(mut self, origin: Point) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// A grid from a character map like the one of day 8 or day 12. Blank
+    /// lines are skipped and every row has to be as wide as the first one.
+    /// `convert` returns `None` for characters that don't belong on the map.
+    pub fn parse<F>(input: &str, expected: &str, mut convert: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for (i, line) in input.lines().enumerate().filter(|(_, l)| !l.trim().is_empty()) {
+            let line = line.trim_end();
+            let error = |column: usize, expected: &str, found: &str| ParseError {
+                text: line.to_string(),
+                ..ParseError::new(column, expected, found).at_line(i + 1)
+            };
+            let row_start = cells.len();
+
+            for (x, c) in line.chars().enumerate() {
+                match convert(c) {
+                    Some(cell) => cells.push(cell),
+                    None => return Err(error(x + 1, expected, &c.to_string())),
+                }
+            }
+
+            let row_width = cells.len() - row_start;
+            match width {
+                Some(width) if width != row_width => {
+                    let found = line.chars().skip(width).collect::<String>();
+                    return Err(error(width.min(row_width) + 1, &format!("a row of {} cells", width), &found));
+                }
+                _ => width = Some(row_width),
+            }
+            height += 1;
+        }
+
+        Ok(Self::from_vec(width.unwrap_or(0), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn origin(&self) -> Point {
+        self.origin
+    }
+
+    /// The bottom right cell.
+    pub fn end(&self) -> Point {
+        (self.origin.0 + self.width as isize - 1, self.origin.1 + self.height as isize - 1)
+    }
+
+    fn index_of(&self, (x, y): Point) -> Option<usize> {
+        let x = usize::try_from(x - self.origin.0).ok().filter(|x| *x < self.width)?;
+        let y = usize::try_from(y - self.origin.1).ok().filter(|y| *y < self.height)?;
+
+        Some(y * self.width + x)
+    }
+
+    fn point_of(&self, index: usize) -> Point {
+        (self.origin.0 + (index % self.width) as isize, self.origin.1 + (index / self.width) as isize)
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.index_of(point).is_some()
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|i| &mut self.cells[i])
+    }
+
+    /// Every point of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.cells.len()).map(|i| self.point_of(i))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().enumerate().map(|(i, cell)| (self.point_of(i), cell))
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn position<P: Fn(&T) -> bool>(&self, predicate: P) -> Option<Point> {
+        self.cells.iter().position(predicate).map(|i| self.point_of(i))
+    }
+
+    /// The neighbours of `point` in [`DIRECTIONS_4`] that are on the grid.
+    pub fn neighbours_4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        DIRECTIONS_4.into_iter().map(move |d| step(point, d)).filter(|p| self.contains(*p))
+    }
+
+    /// Like [`Grid::neighbours_4`], diagonals included.
+    pub fn neighbours_8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        DIRECTIONS_8.into_iter().map(move |d| step(point, d)).filter(|p| self.contains(*p))
+    }
+
+    /// Cells from next to `from` towards `direction` up to the edge of the
+    /// grid, `from` itself not included.
+    pub fn ray(&self, from: Point, direction: Point) -> impl Iterator<Item = (Point, &T)> {
+        iter::successors(Some(step(from, direction)), move |p| Some(step(*p, direction)))
+            .map_while(|p| Some((p, self.get(p)?)))
+    }
+
+    /// Row `y`, counted from the top of the grid rather than from the origin.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks(0) panics, a grid without columns has no rows to give
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Column `x`, counted from the left of the grid rather than from the
+    /// origin.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1)).take(self.height)
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, convert: F) -> Grid<U> {
+        Grid { cells: self.cells.iter().map(convert).collect(), width: self.width, height: self.height, origin: self.origin }
+    }
+
+    /// Grows the grid so `point` is on it, filling new cells with `fill`.
+    pub fn grow_to_include(&mut self, point: Point, fill: T)
+    where
+        T: Clone,
+    {
+        if self.contains(point) {
+            return;
+        }
+        if self.cells.is_empty() {
+            *self = Grid::new(1, 1, fill).with_origin(point);
+            return;
+        }
+
+        let (end_x, end_y) = self.end();
+        let origin = (self.origin.0.min(point.0), self.origin.1.min(point.1));
+        let width = (end_x.max(point.0) - origin.0 + 1) as usize;
+        let height = (end_y.max(point.1) - origin.1 + 1) as usize;
+        let mut grown = Grid::new(width, height, fill).with_origin(origin);
+
+        for (i, cell) in std::mem::take(&mut self.cells).into_iter().enumerate() {
+            let point = self.point_of(i);
+            grown[point] = cell;
+        }
+
+        *self = grown;
+    }
+}
+
+// derived, it would only exist for `T: Default`
+impl<T> Default for Grid<T> {
+    fn default() -> Self {
+        Self::from_vec(0, 0, vec![])
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        match self.index_of(point) {
+            Some(i) => &self.cells[i],
+            None => panic!("{:?} is not on the grid from {:?} to {:?}", point, self.origin, self.end()),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        match self.index_of(point) {
+            Some(i) => &mut self.cells[i],
+            None => panic!("{:?} is not on the grid from {:?} to {:?}", point, self.origin, self.end()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn digits(input: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(input, "a digit", |c| c.to_digit(10))
+    }
+
+    #[test]
+    fn parse_01() {
+        let grid = digits("123\n456\n\n").unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(1).collect::<Vec<&u32>>(), vec![&2, &5]);
+        assert_eq!(grid.position(|d| *d == 5), Some((1, 1)));
+
+        let err = digits("123\n4x6\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 2, "x"));
+
+        let err = digits("123\n45\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.expected.as_str()), (2, 3, "a row of 3 cells"));
+    }
+
+    #[test]
+    fn neighbours_01() {
+        let grid = digits("123\n456\n789").unwrap();
+
+        assert_eq!(grid.neighbours_4((0, 0)).collect::<Vec<Point>>(), vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours_4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbours_8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbours_8((2, 2)).map(|p| grid[p]).collect::<Vec<u32>>(), vec![6, 8, 5]);
+    }
+
+    #[test]
+    fn ray_01() {
+        let grid = digits("123\n456\n789").unwrap();
+
+        assert_eq!(grid.ray((0, 1), RIGHT).map(|(_, d)| *d).collect::<Vec<u32>>(), vec![5, 6]);
+        assert_eq!(grid.ray((2, 2), (-1, -1)).map(|(p, _)| p).collect::<Vec<Point>>(), vec![(1, 1), (0, 0)]);
+        assert_eq!(grid.ray((0, 0), UP).count(), 0);
+    }
+
+    #[test]
+    fn origin_01() {
+        let mut grid = Grid::new(2, 1, '.').with_origin((500, 0));
+        grid[(501, 0)] = '#';
+
+        assert_eq!(grid.get((499, 0)), None);
+        assert_eq!(grid.end(), (501, 0));
+
+        grid.grow_to_include((498, 2), '.');
+        assert_eq!((grid.origin(), grid.width(), grid.height()), ((498, 0), 4, 3));
+        assert_eq!(grid[(501, 0)], '#');
+        assert_eq!(grid.rows().map(|row| row.iter().collect::<String>()).collect::<Vec<String>>(), vec!["...#", "....", "...."]);
+    }
+}
@@ -0,0 +1,86 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+pub type Rgb = [u8; 3];
+
+/// An RGB image drawn one cell at a time, every cell a `scale` pixels wide
+/// square.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    scale: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// `width` and `height` in cells, all of them `background`.
+    pub fn new(width: usize, height: usize, scale: usize, background: Rgb) -> Self {
+        let scale = scale.max(1);
+        Self { width: width * scale, height: height * scale, scale, pixels: vec![background; width * height * scale * scale] }
+    }
+
+    /// The size in cells.
+    pub fn cells(&self) -> (usize, usize) {
+        (self.width / self.scale, self.height / self.scale)
+    }
+
+    pub fn set_cell(&mut self, x: usize, y: usize, colour: Rgb) {
+        for py in y * self.scale..(y + 1) * self.scale {
+            let row = py * self.width;
+            self.pixels[row + x * self.scale..row + (x + 1) * self.scale].fill(colour);
+        }
+    }
+
+    /// Binary PPM, readable by about anything that reads images.
+    pub fn write_ppm<W: Write>(&self, mut out: W) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.pixels.concat())
+    }
+
+    pub fn write_png<W: Write>(&self, out: W) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(out, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let mut writer = encoder.write_header().map_err(io::Error::other)?;
+        writer.write_image_data(&self.pixels.concat()).map_err(io::Error::other)
+    }
+
+    /// PNG for paths ending in `.png`, PPM for everything else.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let out = BufWriter::new(File::create(path)?);
+
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("png") => self.write_png(out),
+            _ => self.write_ppm(out),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const AIR: Rgb = [24, 24, 32];
+    const SAND: Rgb = [232, 192, 96];
+
+    #[test]
+    fn write_ppm_01() {
+        let mut image = Image::new(2, 1, 2, AIR);
+        image.set_cell(1, 0, SAND);
+
+        let mut ppm = vec![];
+        image.write_ppm(&mut ppm).unwrap();
+
+        assert_eq!(image.cells(), (2, 1));
+        assert!(ppm.starts_with(b"P6\n4 2\n255\n"));
+        assert_eq!(ppm.len(), 11 + 4 * 2 * 3);
+        assert_eq!(&ppm[11..23], &[AIR, AIR, SAND, SAND].concat()[..]);
+
+        let mut png = vec![];
+        image.write_png(&mut png).unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+    }
+}
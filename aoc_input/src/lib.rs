@@ -0,0 +1,153 @@
+use std::env;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process;
+
+mod parse;
+
+pub use parse::{parse_lines, ParseError, Tokens};
+
+/// Where the puzzle input comes from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Source<'a> {
+    Path(&'a Path),
+    Stdin,
+    Embedded(&'a str),
+}
+
+impl<'a> Source<'a> {
+    /// `-` means stdin, everything else is a path.
+    pub fn from_arg(arg: &'a str) -> Self {
+        match arg {
+            "-" => Source::Stdin,
+            path => Source::Path(Path::new(path)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound { path: PathBuf, cwd: Option<PathBuf> },
+    Io { path: PathBuf, source: io::Error },
+    Stdin(io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound { path, cwd: Some(cwd) } if path.is_relative() => write!(
+                f,
+                "input file '{}' not found in {} - run from the day's crate directory or pass the path explicitly",
+                path.display(),
+                cwd.display()
+            ),
+            InputError::NotFound { path, .. } => write!(f, "input file '{}' not found", path.display()),
+            InputError::Io { path, source } => write!(f, "could not read '{}': {}", path.display(), source),
+            InputError::Stdin(source) => write!(f, "could not read input from stdin: {}", source),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::NotFound { .. } => None,
+            InputError::Io { source, .. } | InputError::Stdin(source) => Some(source),
+        }
+    }
+}
+
+pub fn load(source: Source) -> Result<String, InputError> {
+    match source {
+        Source::Path(path) => read_file(path),
+        Source::Stdin => read_stdin(),
+        Source::Embedded(input) => Ok(normalize(input)),
+    }
+}
+
+pub fn read_file<P: AsRef<Path>>(path: P) -> Result<String, InputError> {
+    let path = path.as_ref();
+
+    match fs::read_to_string(path) {
+        Ok(input) => Ok(normalize(&input)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Err(InputError::NotFound {
+            path: path.to_path_buf(),
+            cwd: env::current_dir().ok(),
+        }),
+        Err(source) => Err(InputError::Io { path: path.to_path_buf(), source }),
+    }
+}
+
+pub fn read_lines<P: AsRef<Path>>(path: P) -> Result<Vec<String>, InputError> {
+    Ok(read_file(path)?.lines().map(|l| l.to_string()).collect())
+}
+
+pub fn read_stdin() -> Result<String, InputError> {
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input).map_err(InputError::Stdin)?;
+
+    Ok(normalize(&input))
+}
+
+/// Turns CRLF into LF and drops trailing newlines, so every day sees the
+/// same text no matter where the input was saved.
+pub fn normalize(input: &str) -> String {
+    input.replace("\r\n", "\n").trim_end_matches(['\n', '\r']).to_string()
+}
+
+/// Groups of lines separated by blank lines, e.g. the elves of day 1 or the
+/// monkeys of day 11.
+pub fn blocks(input: &str) -> impl Iterator<Item = &str> {
+    input
+        .split("\n\n")
+        .map(|block| block.trim_matches('\n'))
+        .filter(|block| !block.is_empty())
+}
+
+pub fn line_blocks(input: &str) -> impl Iterator<Item = Vec<&str>> {
+    blocks(input).map(|block| block.lines().collect())
+}
+
+/// For binaries and `part_xx` helpers: print the error and quit instead of
+/// panicking. Generic so it fits into `unwrap_or_else` for both
+/// [`InputError`] and [`ParseError`].
+pub fn exit<T, E: Display>(err: E) -> T {
+    eprintln!("error: {}", err);
+    process::exit(1)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn normalize_01() {
+        assert_eq!(normalize("a\r\nb\r\n\r\n"), "a\nb");
+        assert_eq!(normalize("  a\n b \n\n\n"), "  a\n b ");
+    }
+
+    #[test]
+    fn blocks_01() {
+        let input = normalize("1\n2\n\n3\n\n\n4\n5\n");
+
+        assert_eq!(blocks(&input).collect::<Vec<&str>>(), vec!["1\n2", "3", "4\n5"]);
+        assert_eq!(line_blocks(&input).last().unwrap(), vec!["4", "5"]);
+    }
+
+    #[test]
+    fn load_01() {
+        assert_eq!(load(Source::Embedded("x\r\ny\n")).unwrap(), "x\ny");
+        assert_eq!(Source::from_arg("-"), Source::Stdin);
+    }
+
+    #[test]
+    fn read_file_not_found() {
+        let err = read_file("assets/does_not_exist.txt").unwrap_err();
+
+        assert!(matches!(err, InputError::NotFound { .. }));
+        assert!(err.to_string().contains("assets/does_not_exist.txt"));
+    }
+}
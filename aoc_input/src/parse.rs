@@ -0,0 +1,207 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// A piece of puzzle input that could not be parsed. `line` and `column` are
+/// 1-based, `line` is 0 until the caller knows which line it was looking at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+    /// The offending line, used to point at the column. Empty if unknown.
+    pub text: String,
+}
+
+impl ParseError {
+    pub fn new(column: usize, expected: impl Into<String>, found: impl Into<String>) -> Self {
+        Self { line: 0, column, expected: expected.into(), found: found.into(), text: String::new() }
+    }
+
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    /// For errors numbered within a block that starts `offset` lines further
+    /// down the input.
+    pub fn shift_lines(mut self, offset: usize) -> Self {
+        self.line += offset;
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line > 0 {
+            write!(f, "line {}, ", self.line)?;
+        }
+        write!(f, "column {}: expected {}, ", self.column, self.expected)?;
+        match self.found.as_str() {
+            "" => write!(f, "found end of line")?,
+            found => write!(f, "found '{}'", found)?,
+        }
+        if !self.text.is_empty() {
+            write!(f, "\n    {}\n    {}^", self.text, " ".repeat(self.column.saturating_sub(1)))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses every non-blank line of `input`, with trailing whitespace removed.
+/// Errors get the number of the line they came from.
+pub fn parse_lines<T, F>(input: &str, mut parse: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| parse(line.trim_end()).map_err(|e| e.at_line(i + 1)))
+        .collect()
+}
+
+/// Walks a line one whitespace separated token at a time. Trailing `,`, `:`
+/// and `;` are dropped from tokens, so `x=2,` and `Operation:` read as `x=2`
+/// and `Operation`. Every error points at the column of the token it's about.
+#[derive(Clone)]
+pub struct Tokens<'a> {
+    line: &'a str,
+    pos: usize,
+}
+
+impl<'a> Tokens<'a> {
+    pub fn new(line: &'a str) -> Self {
+        Self { line, pos: 0 }
+    }
+
+    fn next_token(&mut self) -> Option<(usize, &'a str)> {
+        let rest = &self.line[self.pos..];
+        let trimmed = rest.trim_start();
+        let start = self.pos + rest.len() - trimmed.len();
+        let len = trimmed.find(char::is_whitespace).unwrap_or(trimmed.len());
+        self.pos = start + len;
+
+        match len {
+            0 => None,
+            _ => Some((start, trimmed[..len].trim_end_matches([',', ':', ';']))),
+        }
+    }
+
+    fn expect_token(&mut self, expected: &str) -> Result<(usize, &'a str), ParseError> {
+        let end = self.line.trim_end().len();
+        self.next_token().ok_or_else(|| self.error(end, expected, ""))
+    }
+
+    /// Error for whatever starts at byte `offset` of the line.
+    fn error(&self, offset: usize, expected: &str, found: &str) -> ParseError {
+        ParseError {
+            text: self.line.to_string(),
+            ..ParseError::new(self.line[..offset].chars().count() + 1, expected, found)
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.line[self.pos..].trim().is_empty()
+    }
+
+    pub fn word(&mut self, expected: &str) -> Result<&'a str, ParseError> {
+        Ok(self.expect_token(expected)?.1)
+    }
+
+    pub fn keyword(&mut self, keyword: &str) -> Result<(), ParseError> {
+        self.one_of(&[keyword]).map(|_| ())
+    }
+
+    /// Like [`Tokens::keyword`] for words that come in more than one form,
+    /// e.g. `valve` and `valves`.
+    pub fn one_of(&mut self, keywords: &[&str]) -> Result<&'a str, ParseError> {
+        let expected = keywords.iter().map(|k| format!("'{}'", k)).collect::<Vec<String>>().join(" or ");
+        let (offset, token) = self.expect_token(&expected)?;
+
+        match keywords.contains(&token) {
+            true => Ok(token),
+            false => Err(self.error(offset, &expected, token)),
+        }
+    }
+
+    pub fn number<T: FromStr>(&mut self, expected: &str) -> Result<T, ParseError> {
+        self.parse_with(expected, |token| token.parse::<T>().ok())
+    }
+
+    /// Next token turned into a `T` by `convert`, which returns `None` for
+    /// tokens that don't fit.
+    pub fn parse_with<T, F>(&mut self, expected: &str, convert: F) -> Result<T, ParseError>
+    where
+        F: FnOnce(&'a str) -> Option<T>,
+    {
+        let (offset, token) = self.expect_token(expected)?;
+        convert(token).ok_or_else(|| self.error(offset, expected, token))
+    }
+
+    /// A `name=value` token like `x=-2` or `rate=13`.
+    pub fn assignment<T: FromStr>(&mut self, name: &str, expected: &str) -> Result<T, ParseError> {
+        let prefix = format!("{}=", name);
+        let (offset, token) = self.expect_token(&format!("'{}'", prefix))?;
+        let Some(value) = token.strip_prefix(&prefix) else {
+            return Err(self.error(offset, &format!("'{}'", prefix), token));
+        };
+
+        value.parse::<T>().map_err(|_| self.error(offset + prefix.len(), expected, value))
+    }
+
+    pub fn end(&mut self) -> Result<(), ParseError> {
+        match self.next_token() {
+            Some((offset, token)) => Err(self.error(offset, "end of line", token)),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn tokens_01() {
+        let mut tokens = Tokens::new("Sensor at x=2, y=-18: closest");
+
+        assert_eq!(tokens.keyword("Sensor"), Ok(()));
+        assert_eq!(tokens.word("a word"), Ok("at"));
+        assert_eq!(tokens.assignment::<i32>("x", "a number"), Ok(2));
+        assert_eq!(tokens.assignment::<i32>("y", "a number"), Ok(-18));
+        assert!(!tokens.is_empty());
+        assert_eq!(tokens.end().unwrap_err().column, 23);
+    }
+
+    #[test]
+    fn tokens_errors() {
+        let mut tokens = Tokens::new("move x from 1");
+        tokens.keyword("move").unwrap();
+        let err = tokens.number::<i32>("a crate count").unwrap_err();
+
+        assert_eq!(err, ParseError { text: "move x from 1".to_string(), ..ParseError::new(6, "a crate count", "x") });
+        assert_eq!(Tokens::new("tunnel").one_of(&["tunnels", "tunnel"]), Ok("tunnel"));
+
+        let mut tokens = Tokens::new("R   ");
+        tokens.keyword("R").unwrap();
+        let err = tokens.number::<i32>("a step count").unwrap_err();
+
+        assert_eq!((err.column, err.found.as_str()), (2, ""));
+        assert_eq!(err.at_line(4).to_string(), "line 4, column 2: expected a step count, found end of line\n    R   \n     ^");
+    }
+
+    #[test]
+    fn parse_lines_01() {
+        let parsed = parse_lines("1\n2  \n   \n3", |line| Tokens::new(line).number::<i32>("a number"));
+        assert_eq!(parsed, Ok(vec![1, 2, 3]));
+
+        let err = parse_lines("1\n\nx", |line| Tokens::new(line).number::<i32>("a number")).unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+    }
+}
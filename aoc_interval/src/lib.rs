@@ -0,0 +1,257 @@
+use std::ops::RangeInclusive;
+
+use num_traits::PrimInt;
+
+/// A set of integers kept as sorted, inclusive ranges that neither overlap
+/// nor touch, so `1..=3` and `4..=6` are stored as `1..=6`. Ranges with
+/// `start > end` are empty.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<RangeInclusive<T>>,
+}
+
+// derived, it would only exist for `T: Default`
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: vec![] }
+    }
+}
+
+/// `value + 1`, or `None` for the largest `T`.
+fn after<T: PrimInt>(value: T) -> Option<T> {
+    value.checked_add(&T::one())
+}
+
+/// `value - 1`, or `None` for the smallest `T`.
+fn before<T: PrimInt>(value: T) -> Option<T> {
+    value.checked_sub(&T::one())
+}
+
+impl<T: PrimInt> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_range(range: RangeInclusive<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(range);
+        set
+    }
+
+    /// The ranges of the set, sorted.
+    pub fn ranges(&self) -> &[RangeInclusive<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// How many values are in the set. `u128` fits even every `i64`, only a
+    /// set of all `u128` or all `i128` values has more and gets `u128::MAX`.
+    pub fn len(&self) -> u128 {
+        // negative values as their two's complement, the difference between
+        // the ends comes out right either way
+        let bits = |value: T| value.to_u128().or_else(|| value.to_i128().map(|v| v as u128)).unwrap();
+
+        self.ranges
+            .iter()
+            .map(|r| bits(*r.end()).wrapping_sub(bits(*r.start())))
+            .fold(0, |len, width| len.saturating_add(width).saturating_add(1))
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|r| *r.end() < value);
+        self.ranges.get(i).is_some_and(|r| *r.start() <= value)
+    }
+
+    /// Adds every value of `range`, merging it with the ranges it overlaps
+    /// or touches.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        // the first range ending right before `start` or later, and the
+        // first one starting more than one after `end`
+        let first = self.ranges.partition_point(|r| after(*r.end()).is_some_and(|e| e < start));
+        let last = self.ranges.partition_point(|r| before(*r.start()).is_none_or(|s| s <= end));
+
+        if first < last {
+            start = start.min(*self.ranges[first].start());
+            end = end.max(*self.ranges[last - 1].end());
+        }
+        self.ranges.splice(first..last, [start..=end]);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.extend(other.ranges.iter().cloned());
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let start = *a.start().max(b.start());
+            let end = *a.end().min(b.end());
+            if start <= end {
+                ranges.push(start..=end);
+            }
+
+            match a.end() < b.end() {
+                true => i += 1,
+                false => j += 1,
+            }
+        }
+
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        self.intersection(&other.complement(T::min_value()..=T::max_value()))
+    }
+
+    /// Every value of `bounds` not in the set.
+    pub fn complement(&self, bounds: RangeInclusive<T>) -> Self {
+        Self { ranges: self.gaps(bounds).collect() }
+    }
+
+    /// The ranges of `bounds` between the ranges of the set, in order.
+    pub fn gaps(&self, bounds: RangeInclusive<T>) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        let (low, high) = bounds.into_inner();
+        // where the next gap may start, `None` once past `high`
+        let mut next = (low <= high).then_some(low);
+
+        self.ranges
+            .iter()
+            .map(Some)
+            .chain([None])
+            .filter_map(move |range| {
+                let from = next?;
+                let (to, resume) = match range {
+                    Some(range) if *range.end() < from => return None,
+                    Some(range) => (before(*range.start()), after(*range.end())),
+                    None => (Some(high), None),
+                };
+                next = resume.filter(|r| *r <= high);
+
+                let to = to?.min(high);
+                (from <= to).then_some(from..=to)
+            })
+    }
+}
+
+impl<T: PrimInt> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, ranges: I) {
+        for range in ranges {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T: PrimInt> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(ranges: I) -> Self {
+        let mut set = Self::new();
+        set.extend(ranges);
+        set
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn insert_01() {
+        let set = IntervalSet::from_iter([5..=7, 1..=2, 3..=3, 10..=12, 9..=8]);
+
+        assert_eq!(set.ranges(), &[1..=3, 5..=7, 10..=12]);
+        assert_eq!(set.len(), 9);
+        assert!(set.contains(6) && !set.contains(4) && !set.contains(13));
+
+        let set = set.union(&IntervalSet::from_range(4..=9));
+        assert_eq!(set.ranges(), &[1..=12]);
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn gaps_01() {
+        let set = IntervalSet::from_iter([2..=3, 6..=8]);
+
+        assert_eq!(set.gaps(0..=10).collect::<Vec<_>>(), vec![0..=1, 4..=5, 9..=10]);
+        assert_eq!(set.gaps(3..=7).collect::<Vec<_>>(), vec![4..=5]);
+        assert_eq!(set.gaps(6..=8).count(), 0);
+        assert_eq!(IntervalSet::<i32>::new().gaps(1..=0).count(), 0);
+    }
+
+    #[test]
+    fn limits_01() {
+        let set = IntervalSet::from_iter([250u8..=255, 0..=3]);
+
+        assert_eq!(set.len(), 10);
+        assert_eq!(set.complement(0..=255).ranges(), &[4..=249]);
+        assert_eq!(IntervalSet::from_range(i64::MIN..=i64::MAX).len(), 1 << 64);
+        assert_eq!(IntervalSet::from_range(0..=usize::MAX).len(), usize::MAX as u128 + 1);
+        assert_eq!(IntervalSet::from_iter([0..=1, u128::MAX - 1..=u128::MAX]).len(), 4);
+        assert_eq!(IntervalSet::from_range(i128::MIN + 1..=i128::MAX).len(), u128::MAX);
+        assert_eq!(IntervalSet::from_range(0..=u128::MAX).len(), u128::MAX);
+    }
+
+    /// The set as one flag per `u8`.
+    fn bits(set: &IntervalSet<u8>) -> Vec<bool> {
+        (0..=255).map(|v| set.contains(v)).collect()
+    }
+
+    fn naive(ranges: &[(u8, u8)]) -> Vec<bool> {
+        (0..=255).map(|v| ranges.iter().any(|(s, e)| *s <= v && v <= *e)).collect()
+    }
+
+    fn build(ranges: &[(u8, u8)]) -> IntervalSet<u8> {
+        ranges.iter().map(|(s, e)| *s..=*e).collect()
+    }
+
+    fn ranges() -> impl Strategy<Value = Vec<(u8, u8)>> {
+        prop::collection::vec((any::<u8>(), any::<u8>()), 0..8)
+    }
+
+    proptest! {
+        #[test]
+        fn matches_bitset(a in ranges()) {
+            let set = build(&a);
+
+            prop_assert_eq!(bits(&set), naive(&a));
+            prop_assert_eq!(set.len(), naive(&a).iter().filter(|b| **b).count() as u128);
+            for pair in set.ranges().windows(2) {
+                prop_assert!(*pair[0].end() as u16 + 1 < *pair[1].start() as u16);
+            }
+        }
+
+        #[test]
+        fn set_operations_match_bitset(a in ranges(), b in ranges()) {
+            let (set_a, set_b) = (build(&a), build(&b));
+            let (bits_a, bits_b) = (naive(&a), naive(&b));
+            let zip = |f: fn(bool, bool) -> bool| bits_a.iter().zip(&bits_b).map(|(x, y)| f(*x, *y)).collect::<Vec<bool>>();
+
+            prop_assert_eq!(bits(&set_a.union(&set_b)), zip(|x, y| x || y));
+            prop_assert_eq!(bits(&set_a.intersection(&set_b)), zip(|x, y| x && y));
+            prop_assert_eq!(bits(&set_a.difference(&set_b)), zip(|x, y| x && !y));
+        }
+
+        #[test]
+        fn complement_matches_bitset(a in ranges(), low: u8, high: u8) {
+            let set = build(&a);
+            let expected = naive(&a).iter().enumerate().map(|(v, b)| !b && low as usize <= v && v <= high as usize).collect::<Vec<bool>>();
+
+            let complement = set.complement(low..=high);
+
+            prop_assert_eq!(bits(&complement), expected);
+            prop_assert_eq!(complement.ranges().to_vec(), set.gaps(low..=high).collect::<Vec<_>>());
+        }
+    }
+}
@@ -0,0 +1,259 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// What a step from one state to the next costs, any integer will do.
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<C: Copy + Ord + Default + Add<Output = C>> Cost for C {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    /// From the start the search went out from to the goal, both included.
+    pub states: Vec<S>,
+    pub cost: C,
+}
+
+impl<S, C> Path<S, C> {
+    pub fn steps(&self) -> usize {
+        self.states.len() - 1
+    }
+
+    pub fn start(&self) -> &S {
+        &self.states[0]
+    }
+
+    pub fn goal(&self) -> &S {
+        &self.states[self.states.len() - 1]
+    }
+}
+
+/// Every state a search has reached, numbered in the order they were
+/// reached in, so the queues only have to hold numbers.
+struct Explored<S, C> {
+    states: Vec<S>,
+    index: HashMap<S, usize>,
+    cost: Vec<C>,
+    parent: Vec<Option<usize>>,
+}
+
+impl<S: Clone + Eq + Hash, C: Cost> Explored<S, C> {
+    fn new() -> Self {
+        Self { states: vec![], index: HashMap::new(), cost: vec![], parent: vec![] }
+    }
+
+    /// Records reaching `state` for `cost` coming from `parent`. `None` if
+    /// it had already been reached at least as cheaply.
+    fn reach(&mut self, state: S, cost: C, parent: Option<usize>) -> Option<usize> {
+        match self.index.get(&state) {
+            Some(&i) if self.cost[i] <= cost => None,
+            Some(&i) => {
+                self.cost[i] = cost;
+                self.parent[i] = parent;
+                Some(i)
+            }
+            None => {
+                let i = self.states.len();
+                self.index.insert(state.clone(), i);
+                self.states.push(state);
+                self.cost.push(cost);
+                self.parent.push(parent);
+                Some(i)
+            }
+        }
+    }
+
+    fn path(&self, goal: usize) -> Path<S, C> {
+        let mut states = vec![self.states[goal].clone()];
+        let mut current = goal;
+        while let Some(parent) = self.parent[current] {
+            states.push(self.states[parent].clone());
+            current = parent;
+        }
+        states.reverse();
+
+        Path { states, cost: self.cost[goal] }
+    }
+}
+
+/// Searches the graph `neighbours` describes: handed a state it returns the
+/// states one step away along with what the step costs. All searches start
+/// from every state in `starts` at once and stop at the first state
+/// `is_goal` holds for.
+pub struct Pathfinder<N> {
+    neighbours: N,
+}
+
+impl<N> Pathfinder<N> {
+    pub fn new(neighbours: N) -> Self {
+        Self { neighbours }
+    }
+
+    /// Breadth first, finds the goal the fewest steps away whatever the
+    /// steps cost. The cost of the path is still what its steps add up to.
+    pub fn bfs<S, C, I, G>(&mut self, starts: impl IntoIterator<Item = S>, mut is_goal: G) -> Option<Path<S, C>>
+    where
+        N: FnMut(&S) -> I,
+        I: IntoIterator<Item = (S, C)>,
+        S: Clone + Eq + Hash,
+        C: Cost,
+        G: FnMut(&S) -> bool,
+    {
+        let mut explored = Explored::new();
+        let mut to_visit = starts
+            .into_iter()
+            .filter_map(|start| explored.reach(start, C::default(), None))
+            .collect::<VecDeque<usize>>();
+
+        while let Some(i) = to_visit.pop_front() {
+            if is_goal(&explored.states[i]) {
+                return Some(explored.path(i));
+            }
+
+            for (next, cost) in (self.neighbours)(&explored.states[i]) {
+                if !explored.index.contains_key(&next) {
+                    to_visit.extend(explored.reach(next, explored.cost[i] + cost, Some(i)));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// The cheapest path to a goal.
+    pub fn dijkstra<S, C, I, G>(&mut self, starts: impl IntoIterator<Item = S>, is_goal: G) -> Option<Path<S, C>>
+    where
+        N: FnMut(&S) -> I,
+        I: IntoIterator<Item = (S, C)>,
+        S: Clone + Eq + Hash,
+        C: Cost,
+        G: FnMut(&S) -> bool,
+    {
+        self.astar(starts, is_goal, |_| C::default())
+    }
+
+    /// Like [`Pathfinder::dijkstra`], looking at the states `heuristic`
+    /// thinks are closest to a goal first. The path is only guaranteed to
+    /// be the cheapest if `heuristic` never overestimates what is left.
+    pub fn astar<S, C, I, G, H>(&mut self, starts: impl IntoIterator<Item = S>, is_goal: G, heuristic: H) -> Option<Path<S, C>>
+    where
+        N: FnMut(&S) -> I,
+        I: IntoIterator<Item = (S, C)>,
+        S: Clone + Eq + Hash,
+        C: Cost,
+        G: FnMut(&S) -> bool,
+        H: FnMut(&S) -> C,
+    {
+        let (explored, goal) = self.best_first(starts, is_goal, heuristic);
+
+        goal.map(|goal| explored.path(goal))
+    }
+
+    /// The cheapest cost from the closest start to every state reachable.
+    pub fn distances<S, C, I>(&mut self, starts: impl IntoIterator<Item = S>) -> HashMap<S, C>
+    where
+        N: FnMut(&S) -> I,
+        I: IntoIterator<Item = (S, C)>,
+        S: Clone + Eq + Hash,
+        C: Cost,
+    {
+        let (explored, _) = self.best_first(starts, |_| false, |_| C::default());
+
+        explored.states.into_iter().zip(explored.cost).collect()
+    }
+
+    fn best_first<S, C, I, G, H>(
+        &mut self,
+        starts: impl IntoIterator<Item = S>,
+        mut is_goal: G,
+        mut heuristic: H,
+    ) -> (Explored<S, C>, Option<usize>)
+    where
+        N: FnMut(&S) -> I,
+        I: IntoIterator<Item = (S, C)>,
+        S: Clone + Eq + Hash,
+        C: Cost,
+        G: FnMut(&S) -> bool,
+        H: FnMut(&S) -> C,
+    {
+        let mut explored = Explored::new();
+        let mut to_visit = BinaryHeap::new();
+
+        for start in starts {
+            if let Some(i) = explored.reach(start, C::default(), None) {
+                to_visit.push(Reverse((heuristic(&explored.states[i]), C::default(), i)));
+            }
+        }
+
+        while let Some(Reverse((_, cost, i))) = to_visit.pop() {
+            // reached again more cheaply after this entry was queued
+            if cost > explored.cost[i] {
+                continue;
+            }
+            if is_goal(&explored.states[i]) {
+                return (explored, Some(i));
+            }
+
+            for (next, step) in (self.neighbours)(&explored.states[i]) {
+                if let Some(j) = explored.reach(next, cost + step, Some(i)) {
+                    to_visit.push(Reverse((cost + step + heuristic(&explored.states[j]), cost + step, j)));
+                }
+            }
+        }
+
+        (explored, None)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// 0 -1-> 1 -1-> 2 -1-> 3, with a shortcut 0 -5-> 3 and 4 on its own.
+    fn graph(state: &u32) -> Vec<(u32, u32)> {
+        match state {
+            0 => vec![(1, 1), (3, 5)],
+            1 => vec![(2, 1)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn bfs_01() {
+        let path = Pathfinder::new(graph).bfs([0], |s| *s == 3).unwrap();
+
+        assert_eq!(path, Path { states: vec![0, 3], cost: 5 });
+        assert_eq!(Pathfinder::new(graph).bfs([2, 1], |s| *s == 3).unwrap().states, vec![2, 3]);
+        assert_eq!(Pathfinder::new(graph).bfs([0], |s| *s == 4), None);
+    }
+
+    #[test]
+    fn dijkstra_01() {
+        let path = Pathfinder::new(graph).dijkstra([0], |s| *s == 3).unwrap();
+
+        assert_eq!(path, Path { states: vec![0, 1, 2, 3], cost: 3 });
+        assert_eq!((path.steps(), path.start(), path.goal()), (3, &0, &3));
+        assert_eq!(Pathfinder::new(graph).distances([0]), HashMap::from([(0, 0), (1, 1), (2, 2), (3, 3)]));
+    }
+
+    #[test]
+    fn astar_01() {
+        let walls = ["....#", ".##.#", "...#.", "#...."];
+        let neighbours = |&(x, y): &(i32, i32)| {
+            [(0, 1), (1, 0), (0, -1), (-1, 0)]
+                .into_iter()
+                .map(move |(dx, dy)| (x + dx, y + dy))
+                .filter(|&(x, y)| walls.get(y as usize).and_then(|row| row.chars().nth(x as usize)) == Some('.'))
+                .map(|point| (point, 1))
+        };
+        let manhattan = |&(x, y): &(i32, i32)| (4 - x).abs() + (3 - y).abs();
+
+        let astar = Pathfinder::new(neighbours).astar([(0, 0)], |p| *p == (4, 3), manhattan).unwrap();
+        let dijkstra = Pathfinder::new(neighbours).dijkstra([(0, 0)], |p| *p == (4, 3)).unwrap();
+
+        assert_eq!((astar.cost, dijkstra.cost), (7, 7));
+        assert_eq!(astar.states.len(), 8);
+    }
+}
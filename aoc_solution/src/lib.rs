@@ -0,0 +1,119 @@
+use std::fmt::{self, Display};
+
+use aoc_input::ParseError;
+
+/// A single day of Advent of Code. `parse` is handed the raw puzzle input and
+/// stores whatever the parts need, so both parts can be run (and timed) on
+/// their own without reading or parsing the input again. Malformed input is
+/// reported by `parse`, the parts themselves can assume it was valid.
+pub trait Solution {
+    type Part1: Display;
+    type Part2: Display;
+
+    /// `false` for a part that isn't solved yet, which then answers with
+    /// [`Unsolved`] and is skipped by the runner.
+    const PART1_SOLVED: bool = true;
+    const PART2_SOLVED: bool = true;
+
+    /// (year, day)
+    fn name(&self) -> (usize, usize);
+    fn parse(&mut self, input: &str) -> Result<(), ParseError>;
+    fn part1(&self) -> Self::Part1;
+    fn part2(&self) -> Self::Part2;
+}
+
+/// Answer for a part that has not been solved yet, see
+/// [`Solution::PART1_SOLVED`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "not implemented")
+    }
+}
+
+/// Object safe version of [`Solution`] so days with different answer types
+/// can be kept in one list. Every `Solution` gets it for free.
+pub trait AnySolution {
+    fn id(&self) -> (usize, usize);
+    fn load(&mut self, input: &str) -> Result<(), ParseError>;
+    /// `false` for parts marked as not solved or parts that don't exist.
+    fn solved(&self, part: u8) -> bool;
+    /// `None` whenever [`AnySolution::solved`] is `false`.
+    fn answer(&self, part: u8) -> Option<String>;
+}
+
+impl<T: Solution> AnySolution for T {
+    fn id(&self) -> (usize, usize) {
+        self.name()
+    }
+
+    fn load(&mut self, input: &str) -> Result<(), ParseError> {
+        self.parse(input)
+    }
+
+    fn solved(&self, part: u8) -> bool {
+        match part {
+            1 => T::PART1_SOLVED,
+            2 => T::PART2_SOLVED,
+            _ => false,
+        }
+    }
+
+    fn answer(&self, part: u8) -> Option<String> {
+        match part {
+            1 if self.solved(1) => Some(self.part1().to_string()),
+            2 if self.solved(2) => Some(self.part2().to_string()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Default)]
+    struct Sum {
+        numbers: Vec<i32>,
+    }
+
+    impl Solution for Sum {
+        type Part1 = i32;
+        type Part2 = Unsolved;
+
+        const PART2_SOLVED: bool = false;
+
+        fn name(&self) -> (usize, usize) {
+            (2022, 0)
+        }
+
+        fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+            self.numbers = aoc_input::parse_lines(input, |line| aoc_input::Tokens::new(line).number("a number"))?;
+            Ok(())
+        }
+
+        fn part1(&self) -> i32 {
+            self.numbers.iter().sum()
+        }
+
+        fn part2(&self) -> Unsolved {
+            Unsolved
+        }
+    }
+
+    #[test]
+    fn any_solution_01() {
+        let mut solution: Box<dyn AnySolution> = Box::<Sum>::default();
+        solution.load("1\n2\n3\n").unwrap();
+
+        assert_eq!(solution.id(), (2022, 0));
+        assert_eq!(solution.answer(1), Some("6".to_string()));
+        assert!(solution.solved(1));
+        assert!(!solution.solved(2));
+        assert_eq!(solution.answer(2), None);
+        assert_eq!(solution.answer(3), None);
+        assert_eq!(solution.load("1\nx\n").unwrap_err().line, 2);
+    }
+}
@@ -1,9 +1,6 @@
-use std::process;
 use std::num::ParseIntError;
-use std::fs::File;
-use std::io::{ self, BufRead };
 
-use crate::helpers::read_lines;
+use aoc_input::ParseError;
 
 
 pub fn count_or_sum(line: &str, current: i32) -> Result<i32, ParseIntError> {
@@ -12,40 +9,49 @@ pub fn count_or_sum(line: &str, current: i32) -> Result<i32, ParseIntError> {
     }
         
 
-    let num = match line.parse::<i32>() {
+    match line.parse::<i32>() {
         Ok(number) => Ok( number+ current ),
         Err(err) => Err(err)
-    };
+    }
+}
 
-    num
+pub fn get_max(input: &str, top_count:i32) -> i32 {
+    let sums = get_sums(input).unwrap_or_else(aoc_input::exit);
+    let sum = sum_of_top(&sums, top_count);
+    println!("Maximum value: {:?}", sum);
+    sum
 }
 
-pub fn get_max(lines: io::Lines<io::BufReader<File>>, top_count:i32) -> i32 {
-    let mut sums = Vec::<i32>::new();
-    let mut current = 0;
-    for line in lines {
-        if let Ok(line) = line {
-            if line.is_empty() {                
-                sums.push(current);
-                current = 0;
-            } else {
-                current = count_or_sum(&line, current).unwrap();
-            }            
+/// The calories every elf carries, smallest first. Elves are separated by
+/// blank lines.
+pub fn get_sums(input: &str) -> Result<Vec<i32>, ParseError> {
+    let mut sums = vec![];
+    let mut current = None;
+
+    for (i, line) in input.lines().map(str::trim_end).enumerate() {
+        if line.is_empty() {
+            sums.extend(current.take());
+            continue;
         }
+
+        let sum = count_or_sum(line, current.unwrap_or(0)).map_err(|_| ParseError {
+            text: line.to_string(),
+            ..ParseError::new(1, "a number of calories", line).at_line(i + 1)
+        })?;
+        current = Some(sum);
     }
+    sums.extend(current);
 
     sums.sort();
-    let mut sum = 0;
-    for i in 0..top_count {
-        let current_value = sums[sums.len()-1-(i as usize)];
-        println!("Current value beeing added: {:?}", current_value );
-        sum = sum + current_value;
-    }
-    println!("Maximum value: {:?}", sum);
-    sum
+    Ok(sums)
+}
+
+pub fn sum_of_top(sums: &[i32], top_count:i32) -> i32 {
+    sums.iter().rev().take(top_count as usize).sum()
 }
 
 #[cfg(test)]
+#[allow(non_snake_case)]
 mod tests {
     use super::*;
 
@@ -71,11 +77,28 @@ mod tests {
 
     #[test]
     fn write_sums_to_vector__simple_block_works(){
-        let test_file = read_lines("test/my_funcs/write_sums_to_vector_01.txt").unwrap();
+        let test_file = aoc_input::read_file("test/my_funcs/write_sums_to_vector_01.txt").unwrap();
 
-        let max = get_max(test_file);
+        let max = get_max(&test_file, 1);
 
         assert_eq!(max, 15);
 
     }
+
+    #[test]
+    fn write_sums_to_vector__last_block_without_blank_line_counts(){
+        let test_file = aoc_input::read_file("test/my_funcs/write_sums_to_vector_02.txt").unwrap();
+
+        let max = get_max(&test_file, 1);
+
+        assert_eq!(max, 4000);
+
+    }
+
+    #[test]
+    fn get_sums__malformed_line_is_reported(){
+        let err = get_sums("1000\n2000\n\n3OOO\n").unwrap_err();
+
+        assert_eq!((err.line, err.column, err.found.as_str()), (4, 1, "3OOO"));
+    }
 }
\ No newline at end of file
@@ -1,10 +1,10 @@
-use std::fs;
 use std::cmp::Ordering;
 use std::error::Error;
 
+use aoc_input::ParseError;
+use aoc_solution::Solution;
+
 mod funcs;
-mod helpers;
-use crate::helpers::read_lines;
 
 #[derive(Debug)]
 pub struct Config {
@@ -37,23 +37,61 @@ pub fn run(config:Config) -> Result<(), Box<dyn Error>>{
 
 pub fn run_part_01(config:Config) -> Result<(), Box<dyn Error>>{
     println!("Running Part 01");
-
-    if let Ok(lines) = read_lines(config.file_path) {
-        funcs::get_max(lines, 1);
-    }    
+    part_01(Some(&config.file_path));
 
     Ok(())
 }
 
 pub fn run_part_02(config:Config) -> Result<(), Box<dyn Error>> {
     println!("Running Part 02");
-    if let Ok(lines) = read_lines(config.file_path) {
-        funcs::get_max(lines, 3);
-    }
+    part_02(Some(&config.file_path));
+
+    Ok(())
+}
 
- Ok(())
+pub fn part_01(path: Option<&str>) -> i32 {
+    let input = aoc_input::read_file(path.unwrap_or("assets/input.txt")).unwrap_or_else(aoc_input::exit);
+    funcs::get_max(&input, 1)
+}
+
+pub fn part_02(path: Option<&str>) -> i32 {
+    let input = aoc_input::read_file(path.unwrap_or("assets/input.txt")).unwrap_or_else(aoc_input::exit);
+    funcs::get_max(&input, 3)
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Default)]
+pub struct AOC_2022_01 {
+    sums: Vec<i32>
+}
+impl AOC_2022_01 {
+    pub fn new() -> Self {
+        Self::default()
+    }
 }
 
+impl Solution for AOC_2022_01 {
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn name(&self) -> (usize, usize) {
+        (2022,1)
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+        self.sums = funcs::get_sums(input)?;
+
+        Ok(())
+    }
+
+    fn part1(&self) -> i32 {
+        funcs::sum_of_top(&self.sums, 1)
+    }
+
+    fn part2(&self) -> i32 {
+        funcs::sum_of_top(&self.sums, 3)
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -61,6 +99,10 @@ mod tests {
 
     #[test]
     fn one_result(){
+        let mut solution = AOC_2022_01::new();
+        solution.parse("1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000").unwrap();
 
+        assert_eq!(solution.part1(), 24000);
+        assert_eq!(solution.part2(), 45000);
     }
 }
\ No newline at end of file
@@ -1,6 +1,5 @@
 #[allow(unused_imports,dead_code,
     unused_variables)]
-
 use day_01::Config;
 use std::process;
 use std::env;
@@ -1,29 +1,102 @@
-mod input_reader;
+use aoc_input::ParseError;
+use aoc_solution::Solution;
+
 
 pub fn run(){
-    let input = input_reader::read_file_in_cwd("assets/input.txt");
+    let input = aoc_input::read_file("assets/input.txt").unwrap_or_else(aoc_input::exit);
 
     part_01(input);
 }
 
-pub fn part_01(input:String){    
-    let elf_calorie_groups: Vec<&str> = input.split("\n\n").collect();
+pub fn part_01(input:String) -> i32 {
+    let all_cals = calories_per_elf(&input).unwrap_or_else(aoc_input::exit);
+    let highest = most_calories(&all_cals);
+    println!("Highest Value: {:?}", highest);
+
+    highest
+}
+
+/// Calories carried by each elf, lowest first. Elves are separated by blank
+/// lines, every other line has to be a number.
+fn calories_per_elf(input: &str) -> Result<Vec<i32>, ParseError> {
     let mut all_cals: Vec<i32> = vec!();
-    
-    for elf_cal_group in elf_calorie_groups.iter() {
-        let mut cals:i32 = 0;
-        let cals_group:Vec<&str> = elf_cal_group.split("\n").collect();       
+    let mut cals: Option<i32> = None;
 
-        for cal in cals_group {
-            if let Ok(cal) = cal.parse::<i32>(){
-                cals += cal;
-            };
-            
-        } 
-        all_cals.push(cals);
+    for (i, line) in input.lines().map(str::trim_end).enumerate() {
+        if line.is_empty() {
+            all_cals.extend(cals.take());
+            continue;
+        }
+
+        let cal = line.parse::<i32>().map_err(|_| ParseError {
+            text: line.to_string(),
+            ..ParseError::new(1, "a number of calories", line).at_line(i + 1)
+        })?;
+        cals = Some(cals.unwrap_or(0) + cal);
     }
+    all_cals.extend(cals);
 
     all_cals.sort();
-    println!("Highest Value: {:?}", all_cals.iter().last().unwrap());
+    Ok(all_cals)
+}
+
+/// 0 without any elves.
+fn most_calories(all_cals: &[i32]) -> i32 {
+    all_cals.last().copied().unwrap_or(0)
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Default)]
+pub struct AOC_2022_01 {
+    all_cals: Vec<i32>
+}
+impl AOC_2022_01 {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Solution for AOC_2022_01 {
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn name(&self) -> (usize, usize) {
+        (2022,1)
+    }
 
-}
\ No newline at end of file
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+        self.all_cals = calories_per_elf(input)?;
+
+        Ok(())
+    }
+
+    fn part1(&self) -> i32 {
+        most_calories(&self.all_cals)
+    }
+
+    fn part2(&self) -> i32 {
+        self.all_cals.iter().rev().take(3).sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn calories_per_elf_01() {
+        assert_eq!(calories_per_elf("1000\n2000\n\n\n500\n"), Ok(vec![500, 3000]));
+        assert_eq!(calories_per_elf(""), Ok(vec![]));
+
+        let err = calories_per_elf("1000\n2000\n\n3OOO\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (4, 1, "3OOO"));
+    }
+
+    #[test]
+    fn solution_01() {
+        let mut solution = AOC_2022_01::new();
+        solution.parse("").unwrap();
+
+        assert_eq!((solution.part1(), solution.part2()), (0, 0));
+    }
+}
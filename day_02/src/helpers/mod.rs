@@ -1,21 +1,16 @@
-use std::path::Path;
-use std::io::{ self, BufRead };
-use std::fs::File;
 use std::env;
 use std::cmp::Ordering;
 
-#[allow(dead_code)]
-
-pub fn read_lines<P>(filename:P) -> io::Result<io::Lines<io::BufReader<File>>>
-where P: AsRef<Path>, {
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
-}
-
 pub struct Config {
     pub part: i32
 }
 
+impl Default for Config {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Config {
     pub fn new() -> Self {
         let args: Vec<String> = env::args().collect();
@@ -1,4 +1,6 @@
 use std::error::Error;
+use aoc_input::{ParseError, Tokens};
+use aoc_solution::Solution;
 pub mod helpers;
 use helpers::Config;
 
@@ -26,16 +28,16 @@ impl Hand {
     pub fn play_against(self, enemies_hand: &Hand) -> i32{
         let mut sum:i32 = 0;
         match self.shape {
-            Shape::Rock => sum = sum + 1,
-            Shape::Paper => sum = sum + 2,
-            Shape::Scissors => sum = sum + 3
+            Shape::Rock => sum += 1,
+            Shape::Paper => sum += 2,
+            Shape::Scissors => sum += 3
         };
 
         if self.shape == enemies_hand.shape {
-            sum = sum + 3;
+            sum += 3;
         }
         if self.beats_shape == enemies_hand.shape {
-            sum = sum + 6;
+            sum += 6;
         }
 
         sum
@@ -48,107 +50,134 @@ struct Round {
     own_hand: Hand
 }
 
-impl Round {
-    pub fn new(line:String, config: &Config) -> Self {
-        if config.part == 1 {
-            return Round::new_01(line);
-        } else {
-            return Round::new_02(line);
-        }
-    }
-
-    pub fn new_01(line: String) -> Self{
-        let input:Vec<&str> = line.split(" ").collect();        
-
-        let enemies_hand = match input[0]{
-            "A" => Hand::new(Shape::Rock),
-            "B" => Hand::new(Shape::Paper),
-            "C" => Hand::new(Shape::Scissors),
-            _ => panic!("unkown input for enemies hand")
-        };
+/// The second column of the strategy guide, the shape to play in part 1
+/// and how the round should end in part 2.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+enum Column {
+    X,
+    Y,
+    Z
+}
 
-        let own_hand = match input[1]{
-            "X" => Hand::new(Shape::Rock),
-            "Y" => Hand::new(Shape::Paper),
-            "Z" => Hand::new(Shape::Scissors),
-            _ => panic!("unknown input for own hand")
+impl Round {
+    pub fn new_01(enemies_shape: Shape, column: Column) -> Self{
+        let own_hand = match column {
+            Column::X => Hand::new(Shape::Rock),
+            Column::Y => Hand::new(Shape::Paper),
+            Column::Z => Hand::new(Shape::Scissors)
         };
 
-        Self { enemies_hand, own_hand }
+        Self { enemies_hand: Hand::new(enemies_shape), own_hand }
     }
 
-    pub fn new_02(line: String) -> Self {
-        let input:Vec<&str> = line.split(" ").collect();        
+    pub fn new_02(enemies_shape: Shape, column: Column) -> Self {
+        let enemies_hand = Hand::new(enemies_shape);
 
-        let enemies_hand = match input[0]{
-            "A" => Hand::new(Shape::Rock),
-            "B" => Hand::new(Shape::Paper),
-            "C" => Hand::new(Shape::Scissors),
-            _ => panic!("unkown input for enemies hand")
-        };
-
-        let own_hand = match input[1]{
-            "X" => Hand::new(enemies_hand.beats_shape),
-            "Y" => Hand::new(enemies_hand.shape),
-            "Z" => Hand::new(Hand::new(enemies_hand.beats_shape).beats_shape),
-            _ => panic!("unknown input for own hand")
+        let own_hand = match column {
+            Column::X => Hand::new(enemies_hand.beats_shape),
+            Column::Y => Hand::new(enemies_hand.shape),
+            Column::Z => Hand::new(Hand::new(enemies_hand.beats_shape).beats_shape)
         };
 
         Self { enemies_hand, own_hand }
     }
 
-
-
     pub fn get_rounds_points(self) -> i32 {
         self.own_hand.play_against(&self.enemies_hand)
     }
 }
 
+fn parse_round(line: &str) -> Result<(Shape, Column), ParseError> {
+    let mut tokens = Tokens::new(line);
+
+    let enemies_shape = tokens.parse_with("'A', 'B' or 'C'", |token| match token {
+        "A" => Some(Shape::Rock),
+        "B" => Some(Shape::Paper),
+        "C" => Some(Shape::Scissors),
+        _ => None
+    })?;
+    let column = tokens.parse_with("'X', 'Y' or 'Z'", |token| match token {
+        "X" => Some(Column::X),
+        "Y" => Some(Column::Y),
+        "Z" => Some(Column::Z),
+        _ => None
+    })?;
+    tokens.end()?;
+
+    Ok((enemies_shape, column))
+}
+
 
 pub fn run(config: &Config) -> Result<(), Box<dyn Error>>{
     if config.part == 1{
-        run_part_01();
+        run_part_01()
     } else {
-        run_part_02();
+        run_part_02()
     }
-
-    Ok(())
 }
 
 pub fn run_part_01() -> Result<(), Box<dyn Error>>{
-
-    let lines = helpers::read_lines("assets/input.txt").unwrap();
-    let mut points:i32 = 0;
-    for line in lines {
-        if let Ok(line) = line {
-            let round = Round::new_01(line);
-            points = points + round.get_rounds_points();
-        }
-    };
-
+    let points = part_01(None);
     println!("Total points: {}", points);
-    
 
     Ok(())
 }
 
 pub fn run_part_02() -> Result<(), Box<dyn Error>>{
-    let lines = helpers::read_lines("assets/input.txt").unwrap();
-    let mut points:i32 = 0;
-    for line in lines {
-        if let Ok(line) = line {
-            let round = Round::new_02(line);
-            points = points + round.get_rounds_points();
-        }
-    };
-
+    let points = part_02(None);
     println!("Total points: {}", points);
-    
 
     Ok(())
 }
 
+pub fn part_01(path: Option<&str>) -> i32 {
+    let mut solution = AOC_2022_02::new();
+    solution.parse(&aoc_input::read_file(path.unwrap_or("assets/input.txt")).unwrap_or_else(aoc_input::exit)).unwrap_or_else(aoc_input::exit);
+    solution.part1()
+}
+
+pub fn part_02(path: Option<&str>) -> i32 {
+    let mut solution = AOC_2022_02::new();
+    solution.parse(&aoc_input::read_file(path.unwrap_or("assets/input.txt")).unwrap_or_else(aoc_input::exit)).unwrap_or_else(aoc_input::exit);
+    solution.part2()
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Default)]
+pub struct AOC_2022_02 {
+    rounds: Vec<(Shape, Column)>
+}
+impl AOC_2022_02 {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Solution for AOC_2022_02 {
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn name(&self) -> (usize, usize) {
+        (2022,2)
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+        self.rounds = aoc_input::parse_lines(input, parse_round)?;
+
+        Ok(())
+    }
+
+    fn part1(&self) -> i32 {
+        self.rounds.iter().map(|(shape, column)| Round::new_01(*shape, *column).get_rounds_points()).sum()
+    }
+
+    fn part2(&self) -> i32 {
+        self.rounds.iter().map(|(shape, column)| Round::new_02(*shape, *column).get_rounds_points()).sum()
+    }
+}
+
 #[cfg(test)]
+#[allow(non_snake_case)]
 mod tests {
     use super::*;
 
@@ -169,7 +198,22 @@ mod tests {
 
         let points = my_hand.play_against(&enemies_hand);
 
-        assert_eq!(points, )
+        assert_eq!(points, 5)
     }
-}
 
+    #[test]
+    fn solution_01(){
+        let mut solution = AOC_2022_02::new();
+        solution.parse("A Y\nB X\nC Z\n").unwrap();
+
+        assert_eq!(solution.part1(), 15);
+        assert_eq!(solution.part2(), 12);
+    }
+
+    #[test]
+    fn parse_round__unknown_shape_is_reported(){
+        let err = AOC_2022_02::new().parse("A Y\nB W\n").unwrap_err();
+
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 3, "W"));
+    }
+}
\ No newline at end of file
@@ -1,8 +1,8 @@
 pub mod helpers;
 use helpers::Config;
 use std::error::Error;
-use std::io;
-use std::fs::File;
+use aoc_input::ParseError;
+use aoc_solution::Solution;
 
 static ALPHABET: [char; 26] = [
     'a', 'b', 'c', 'd', 'e', 
@@ -31,98 +31,141 @@ impl Group {
 }
 
 pub fn run(config: &Config) -> Result<(), Box<dyn Error>>{
-    let lines = helpers::read_lines("assets/input.txt").unwrap();
-
-    if config.part == 1 {
-        run_01(lines).unwrap();
+    let sum = if config.part == 1 {
+        part_01(None)
     } else {
-        run_02(lines).unwrap();
-    }
+        part_02(None)
+    };
+
+    println!("The total sum is: {:?}", sum);
 
     Ok(())
     
 }
 
-pub fn run_01(lines: io::Lines<io::BufReader<File>>) -> Result<(), Box<dyn Error>>{
-    let mut sum:i32 = 0;
-    
-    for line in lines {
-        if let Ok(line) = line {
-            let rs = get_rucksack(&line);
-            let letter = find_matching_character_rucksack(&rs);
-            sum = sum + get_priority(letter);
-        }
-    };
+pub fn part_01(path: Option<&str>) -> i32 {
+    run_01(&load(path))
+}
 
-    println!("The total sum is: {:?}", sum);
+pub fn part_02(path: Option<&str>) -> i32 {
+    run_02(&load(path))
+}
 
-    Ok(())
+fn load(path: Option<&str>) -> Vec<Rucksack> {
+    let input = aoc_input::read_file(path.unwrap_or("assets/input.txt")).unwrap_or_else(aoc_input::exit);
+    parse_rucksacks(&input).unwrap_or_else(aoc_input::exit)
 }
 
-pub fn run_02(lines: io::Lines<io::BufReader<File>>) -> Result<(), Box<dyn Error>>{
-    let mut sum:i32 = 0;
-    let mut g_vec:Vec<String> = vec!();
-    
-    let mut group_index = 0;
-    for line in lines {
-        if let Ok(line) = line {
-            g_vec.push(format!("{}", line));
-            
-            if group_index < 2 {            
-                group_index = group_index + 1;
-            } else {
-                group_index = 0;
-                let group = Group::new(&g_vec[0],&g_vec[1],&g_vec[2]);
-                println!("Group is {:?}", group);
-                g_vec.clear();
-
-                let letter = find_matching_character_group(&group);
-                let priority = get_priority(letter);
-                println!("Priority for this group is: {}", priority);
-                sum = sum + priority;
-            }
+/// Every non-blank line as a rucksack. Items are ASCII letters split evenly
+/// between the two compartments with one item in both, and every three
+/// rucksacks in a row form a group sharing one badge.
+pub fn parse_rucksacks(input: &str) -> Result<Vec<Rucksack>, ParseError> {
+    let lines = input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim_end()))
+        .filter(|(_, line)| !line.is_empty())
+        .collect::<Vec<(usize, &str)>>();
+
+    let rucksacks = lines
+        .iter()
+        .map(|(n, line)| parse_rucksack(line).map_err(|e| ParseError { text: line.to_string(), ..e.at_line(*n) }))
+        .collect::<Result<Vec<Rucksack>, ParseError>>()?;
+
+    for (group, chunk) in rucksacks.chunks(3).zip(lines.chunks(3)) {
+        let (n, line) = chunk[chunk.len() - 1];
+        let badge = match group {
+            [r1, r2, r3] => find_matching_character_group(&Group::new(&r1.2, &r2.2, &r3.2)),
+            _ => return Err(ParseError::new(line.len() + 1, "two more rucksacks to complete the group", "").at_line(n)),
+        };
+        if badge.is_none() {
+            return Err(ParseError {
+                text: line.to_string(),
+                ..ParseError::new(1, "a rucksack sharing an item with the two before it", line).at_line(n)
+            });
         }
     }
 
-    println!("The total sum is: {:?}", sum);
+    Ok(rucksacks)
+}
 
-    Ok(())
+pub fn parse_rucksack(line: &str) -> Result<Rucksack, ParseError> {
+    if let Some((column, item)) = line.chars().enumerate().find(|(_, c)| !c.is_ascii_alphabetic()) {
+        return Err(ParseError::new(column + 1, "an item a-z or A-Z", item.to_string()));
+    }
+    if !line.len().is_multiple_of(2) {
+        return Err(ParseError::new(line.len() + 1, "an even number of items", ""));
+    }
+
+    let rucksack = get_rucksack(line);
+    match find_matching_character_rucksack(&rucksack) {
+        Some(_) => Ok(rucksack),
+        None => Err(ParseError::new(1, "an item in both compartments", line)),
+    }
 }
 
-pub fn get_rucksack(line: &str) -> Rucksack {
-    let (comp1, comp2) = line.split_at(line.len()/2);
-    let result = Rucksack::new( comp1, comp2 );
-    result
+pub fn run_01(rucksacks: &[Rucksack]) -> i32 {
+    rucksacks
+        .iter()
+        .map(|rs| get_priority(find_matching_character_rucksack(rs).expect("checked by parse_rucksack")))
+        .sum()
 }
 
-pub fn find_matching_character_rucksack(rs: &Rucksack) -> char {
-    let comp1: Vec<char> = rs.0.chars().collect();
+pub fn run_02(rucksacks: &[Rucksack]) -> i32 {
+    rucksacks
+        .chunks(3)
+        .map(|group| {
+            let group = Group::new(&group[0].2, &group[1].2, &group[2].2);
+            get_priority(find_matching_character_group(&group).expect("checked by parse_rucksacks"))
+        })
+        .sum()
+}
 
-    let mut result = '0';
-    for letter in comp1 {
-        if let Some(_) = rs.1.find(letter){
-            result = letter;
-        }
+#[allow(non_camel_case_types)]
+#[derive(Default)]
+pub struct AOC_2022_03 {
+    rucksacks: Vec<Rucksack>
+}
+impl AOC_2022_03 {
+    pub fn new() -> Self {
+        Self::default()
     }
-    result
 }
 
-pub fn find_matching_character_group(group: &Group ) -> char {
-    let r1: Vec<char> = group.0.chars().collect();
-    
-    let mut result:char = '0';
-    for letter in r1 {
-        if let Some(_) = group.1.find(letter){
-            if let Some(_) = group.2.find(letter){
-                result = letter;
-            }
-        }
+impl Solution for AOC_2022_03 {
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn name(&self) -> (usize, usize) {
+        (2022,3)
     }
 
-    if result == '0' {
-        panic!("could not find letter");
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+        self.rucksacks = parse_rucksacks(input)?;
+
+        Ok(())
+    }
+
+    fn part1(&self) -> i32 {
+        run_01(&self.rucksacks)
+    }
+
+    fn part2(&self) -> i32 {
+        run_02(&self.rucksacks)
     }
-    result
+}
+
+pub fn get_rucksack(line: &str) -> Rucksack {
+    let (comp1, comp2) = line.split_at(line.len()/2);
+    Rucksack::new( comp1, comp2 )
+}
+
+pub fn find_matching_character_rucksack(rs: &Rucksack) -> Option<char> {
+    rs.0.chars().find(|letter| rs.1.contains(*letter))
+}
+
+pub fn find_matching_character_group(group: &Group ) -> Option<char> {
+    group.0.chars().find(|letter| group.1.contains(*letter) && group.2.contains(*letter))
 }
 
 
@@ -137,15 +180,16 @@ pub fn get_priority(letter:char) -> i32 {
         as i32;
 
     if letter.is_lowercase() {
-        return index+1
+        index+1
     } else {
-        return index+27
+        index+27
     }
     
 }
 
 
 #[cfg(test)]
+#[allow(non_snake_case)]
 mod tests {
     use super::*;
 
@@ -164,7 +208,7 @@ mod tests {
         let found_char = find_matching_character_rucksack(&rs);
         let expected:char = 'd';
 
-        assert_eq!(expected, found_char);
+        assert_eq!(Some(expected), found_char);
     }
 
     #[test]
@@ -186,6 +230,23 @@ mod tests {
         let group = Group::new("abcd","defg", "xydz");
         let letter = find_matching_character_group(&group);
 
-        assert_eq!(letter, 'd');
+        assert_eq!(letter, Some('d'));
+    }
+
+    #[test]
+    fn parse_rucksacks__reports_bad_items(){
+        let err = parse_rucksacks("vJrwpWtwJgWrhcsFMMfFFhFp\nef1g\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 3, "1"));
+
+        let err = parse_rucksack("aébé").unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (2, "é"));
+
+        assert_eq!(parse_rucksack("abcaX").unwrap_err().column, 6);
+        assert_eq!(parse_rucksack("abcd").unwrap_err().expected, "an item in both compartments");
+
+        let group = "vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\nPmmdzqPrVvPwwTWBwgaa\n";
+        assert!(parse_rucksacks(group).is_ok());
+        assert_eq!(parse_rucksacks(&group.replace("PmmdzqPrVvPwwTWBwgaa", "abab")).unwrap_err().line, 3);
+        assert_eq!(parse_rucksacks(&group[..58]).unwrap_err().expected, "two more rucksacks to complete the group");
     }
 }
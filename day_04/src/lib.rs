@@ -1,10 +1,105 @@
-mod input_reader;
 mod part_01;
 mod part_02;
 
+use aoc_input::ParseError;
+use aoc_interval::IntervalSet;
+use aoc_solution::Solution;
+
 pub fn run(){
 
     part_01::run().unwrap();
     part_02::run().unwrap();
     
-}
\ No newline at end of file
+}
+
+/// The sections of one elf's assignment, written like `2-4`. Columns in
+/// errors count from the start of `input`.
+pub(crate) fn assignment(input: &str) -> Result<IntervalSet<i32>, ParseError> {
+    let Some((first, last)) = input.split_once('-') else {
+        return Err(ParseError::new(1, "an assignment like 2-4", input));
+    };
+    let first_section = first.parse::<i32>().map_err(|_| ParseError::new(1, "the first section", first))?;
+    let last_section = last.parse::<i32>().map_err(|_| ParseError::new(first.len() + 2, "the last section", last))?;
+
+    Ok(IntervalSet::from_range(first_section..=last_section))
+}
+
+/// The sections two elves are assigned to clean.
+pub type Pair = (IntervalSet<i32>, IntervalSet<i32>);
+
+/// Every line of the input, the assignments of two elves like `2-4,6-8`.
+pub fn parse_pairs(input: &str) -> Result<Vec<Pair>, ParseError> {
+    aoc_input::parse_lines(input, |line| {
+        let in_line = |e: ParseError| ParseError { text: line.to_string(), ..e };
+        let Some((first, second)) = line.split_once(',') else {
+            return Err(in_line(ParseError::new(line.chars().count() + 1, "',' and a second assignment", "")));
+        };
+
+        let first = assignment(first).map_err(in_line)?;
+        let second = assignment(second).map_err(|e| in_line(ParseError { column: e.column + line.find(',').unwrap() + 1, ..e }))?;
+        Ok((first, second))
+    })
+}
+
+fn load(path: Option<&str>) -> Vec<Pair> {
+    let input = aoc_input::read_file(path.unwrap_or("assets/input.txt")).unwrap_or_else(aoc_input::exit);
+    parse_pairs(&input).unwrap_or_else(aoc_input::exit)
+}
+
+pub fn part_01(path: Option<&str>) -> i32 {
+    part_01::part_01(&load(path))
+}
+
+pub fn part_02(path: Option<&str>) -> i32 {
+    part_02::part_02(&load(path))
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Default)]
+pub struct AOC_2022_04 {
+    pairs: Vec<Pair>
+}
+impl AOC_2022_04 {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Solution for AOC_2022_04 {
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn name(&self) -> (usize, usize) {
+        (2022,4)
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+        self.pairs = parse_pairs(input)?;
+
+        Ok(())
+    }
+
+    fn part1(&self) -> i32 {
+        part_01::part_01(&self.pairs)
+    }
+
+    fn part2(&self) -> i32 {
+        part_02::part_02(&self.pairs)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_pairs_01() {
+        let pairs = parse_pairs("2-4,6-8\n5-7,7-9").unwrap();
+        assert_eq!(pairs[1], (IntervalSet::from_range(5..=7), IntervalSet::from_range(7..=9)));
+
+        let err = parse_pairs("2-4,6-8\n5-7,7-x").unwrap_err();
+        assert_eq!((err.line, err.column, err.expected.as_str()), (2, 7, "the last section"));
+        let err = parse_pairs("2-4 6-8").unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (8, ""));
+    }
+}
@@ -1,57 +1,38 @@
-use std::time::Instant;
 use std::error::Error;
-use crate::input_reader::read_file_in_cwd;
 
-pub fn run() -> Result<(), Box<dyn Error>>{
-    let started = Instant::now();
-    
-    let file = read_file_in_cwd("assets/input.txt");
-    part_01(file);
+use aoc_interval::IntervalSet;
+
+use crate::Pair;
 
-    println!("Part 1 completed in: {:.2?}", started.elapsed());
-    
+pub fn run() -> Result<(), Box<dyn Error>>{
+    let pairs = crate::load(None);
+    println!("Count one contains other: {}", part_01(&pairs));
 
     Ok(())
 }
 
-pub fn part_01(input:String) -> i32 {
-    let lines:Vec<&str> = input.split("\n").collect();
-    let mut one_contains_other_count: i32 = 0;
-    
-    for line in lines.iter() {
-        let ranges:Vec<&str> = line.split(",").collect();
-        if ranges.len() == 2 && one_contains_other(ranges[0], ranges[1]) {
-            one_contains_other_count += 1;
-            //println!("Added one to count: {},{},{}", ranges[0], ranges[1], one_contains_other_count);
-        }        
-    }    
-    
-    println!("Count one contains other: {}", one_contains_other_count);
-
-    one_contains_other_count
+pub fn part_01(pairs: &[Pair]) -> i32 {
+    pairs.iter().filter(|(r1, r2)| one_contains_other(r1, r2)).count() as i32
 }
 
-pub fn one_contains_other(i1: &str, i2: &str) -> bool {
-    let r1: Vec<i32> = i1.split("-").map(|s| { s.parse::<i32>().unwrap() }).collect();
-    let r2: Vec<i32> = i2.split("-").map(|s| { s.parse::<i32>().unwrap() }).collect();
-
-
-    
-    (r1[0] >= r2[0] && r1[1] <= r2[1]) || (r1[0] <= r2[0] && r1[1] >= r2[1])
+pub fn one_contains_other(r1: &IntervalSet<i32>, r2: &IntervalSet<i32>) -> bool {
+    r1.difference(r2).is_empty() || r2.difference(r1).is_empty()
 }
 
 #[cfg(test)]
+#[allow(non_snake_case)]
 mod test {
     use super::*;
+    use crate::{assignment, parse_pairs};
 
     #[test]
     fn one_contains_other__returns_true_if_contained_1(){
         let i1 = "6-6";
         let i2 = "4-6";
 
-        let res = one_contains_other(i1,i2);
+        let res = one_contains_other(&assignment(i1).unwrap(), &assignment(i2).unwrap());
 
-        assert_eq!(res, true)
+        assert!(res)
     }
 
     #[test]
@@ -59,9 +40,9 @@ mod test {
         let i1 = "2-8";
         let i2 = "3-7";
 
-        let res = one_contains_other(i1,i2);
+        let res = one_contains_other(&assignment(i1).unwrap(), &assignment(i2).unwrap());
 
-        assert_eq!(res, true)
+        assert!(res)
     }
 
     #[test]
@@ -69,9 +50,9 @@ mod test {
         let i1 = "1-10";
         let i2 = "2-11";
 
-        let res = one_contains_other(i1,i2);
+        let res = one_contains_other(&assignment(i1).unwrap(), &assignment(i2).unwrap());
 
-        assert_eq!(res, false)
+        assert!(!res)
     }
 
     #[test]
@@ -79,15 +60,15 @@ mod test {
         let i1 = "3-7";
         let i2 = "7-43";
 
-        let res = one_contains_other(i1,i2);
+        let res = one_contains_other(&assignment(i1).unwrap(), &assignment(i2).unwrap());
 
-        assert_eq!(res, false)
+        assert!(!res)
     }
 
     #[test]
     fn part_01__works(){
         let input = "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8";
-        let res = part_01(input.to_string());
+        let res = part_01(&parse_pairs(input).unwrap());
 
         assert_eq!(res, 2);
     }
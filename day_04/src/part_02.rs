@@ -1,56 +1,39 @@
-use std::time::Instant;
 use std::error::Error;
-use crate::input_reader::read_file_in_cwd;
 
+use aoc_interval::IntervalSet;
+
+use crate::Pair;
 
-pub fn run() -> Result<(), Box<dyn Error>>{
-    let started = Instant::now();
-    
-    let file = read_file_in_cwd("assets/input.txt");
-    part_02(file);
 
-    println!("Part 2 completed in: {:.2?}", started.elapsed());
-    
+pub fn run() -> Result<(), Box<dyn Error>>{
+    let pairs = crate::load(None);
+    println!("Overlapping sections: {}", part_02(&pairs));
 
     Ok(())
 }
 
-pub fn part_02(input:String) -> i32 {
-    let lines:Vec<&str> = input.split("\n").collect();
-    let mut overlap_count: i32 = 0;
-    
-    for line in lines.iter() {
-        let ranges:Vec<&str> = line.split(",").collect();
-        if ranges.len() == 2 && ranges_overlap(ranges[0], ranges[1]) {
-            overlap_count += 1;
-            // println!("Added one to count: {},{},{}", ranges[0], ranges[1], overlap_count);
-        } 
-    }    
-    
-    println!("Overlapping sections: {}", overlap_count);
-
-    overlap_count
+pub fn part_02(pairs: &[Pair]) -> i32 {
+    pairs.iter().filter(|(r1, r2)| ranges_overlap(r1, r2)).count() as i32
 }
 
-pub fn ranges_overlap(i1: &str, i2: &str) -> bool {
-    let r1:Vec<i32> = i1.split("-").map(|s| s.parse::<i32>().unwrap()).collect();
-    let r2:Vec<i32> = i2.split("-").map(|s| s.parse::<i32>().unwrap()).collect();
-
-    (r1[0] <= r2[1] && r1[1] >= r2[0]) || (r2[0] <= r1[1] && r2[1] >= r1[0])
+pub fn ranges_overlap(r1: &IntervalSet<i32>, r2: &IntervalSet<i32>) -> bool {
+    !r1.intersection(r2).is_empty()
 }
 
 #[cfg(test)]
+#[allow(non_snake_case)]
 mod test{
     use super::*;
+    use crate::{assignment, parse_pairs};
 
     #[test]
     fn ranges_overlap_01(){
         let r1 = "5-7";
         let r2 = "7-9";
 
-        let res = ranges_overlap(r1,r2);
+        let res = ranges_overlap(&assignment(r1).unwrap(), &assignment(r2).unwrap());
 
-        assert_eq!(res, true)
+        assert!(res)
     }
 
     #[test]
@@ -58,9 +41,9 @@ mod test{
         let r1 = "2-4";
         let r2 = "6-8";
 
-        let res = ranges_overlap(r1,r2);
+        let res = ranges_overlap(&assignment(r1).unwrap(), &assignment(r2).unwrap());
 
-        assert_eq!(res, false)
+        assert!(!res)
     }
 
     #[test]
@@ -68,9 +51,9 @@ mod test{
         let r1 = "2-3";
         let r2 = "4-5";
 
-        let res = ranges_overlap(r1,r2);
+        let res = ranges_overlap(&assignment(r1).unwrap(), &assignment(r2).unwrap());
 
-        assert_eq!(res, false)
+        assert!(!res)
     }
 
     #[test]
@@ -78,15 +61,26 @@ mod test{
         let r1 = "8-96";
         let r2 = "5-6";
 
-        let res = ranges_overlap(r1,r2);
+        let res = ranges_overlap(&assignment(r1).unwrap(), &assignment(r2).unwrap());
 
-        assert_eq!(res, false)
+        assert!(!res)
+    }
+
+    #[test]
+    fn ranges_overlap_05(){
+        let overlap = |r1, r2| ranges_overlap(&assignment(r1).unwrap(), &assignment(r2).unwrap());
+
+        assert!(overlap("2-8", "3-7"));
+        assert!(overlap("3-7", "2-8"));
+        assert!(overlap("6-6", "4-6"));
+        assert!(overlap("7-9", "5-7"));
+        assert!(!overlap("6-8", "2-4"));
     }
 
     #[test]
     fn part_02__works(){
         let input = "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8";
-        let res = part_02(input.to_string());
+        let res = part_02(&parse_pairs(input).unwrap());
 
         assert_eq!(res, 4);
     }
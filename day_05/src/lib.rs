@@ -1,7 +1,10 @@
-mod input_reader;
 mod part_01;
 mod part_02;
 
+use std::collections::VecDeque;
+use aoc_input::{ParseError, Tokens};
+use aoc_solution::Solution;
+
 pub fn run(){
 
     part_01::run().unwrap();
@@ -9,17 +12,147 @@ pub fn run(){
     
 }
 
-#[derive(Debug, PartialEq)]
+pub fn part_01(path: Option<&str>) -> String {
+    part_01::part_01(aoc_input::read_file(path.unwrap_or("assets/input.txt")).unwrap_or_else(aoc_input::exit))
+        .unwrap_or_else(aoc_input::exit)
+}
+
+pub fn part_02(path: Option<&str>) -> String {
+    part_02::part_02(aoc_input::read_file(path.unwrap_or("assets/input.txt")).unwrap_or_else(aoc_input::exit))
+        .unwrap_or_else(aoc_input::exit)
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Default)]
+pub struct AOC_2022_05 {
+    stacks: VecDeque<VecDeque<String>>,
+    instructions: Vec<MoveInstruction>
+}
+impl AOC_2022_05 {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Solution for AOC_2022_05 {
+    type Part1 = String;
+    type Part2 = String;
+
+    fn name(&self) -> (usize, usize) {
+        (2022,5)
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+        let lines:Vec<&str> = input.split("\n").collect();
+        self.stacks = part_01::get_initial_stacks(&lines)?;
+        self.instructions = parse_instructions(&lines, &self.stacks)?;
+
+        Ok(())
+    }
+
+    fn part1(&self) -> String {
+        let mut stacks = self.stacks.clone();
+        for instruction in self.instructions.iter() {
+            part_01::execute_instruction(&mut stacks, *instruction);
+        }
+        top_crates(&stacks)
+    }
+
+    fn part2(&self) -> String {
+        let mut stacks = self.stacks.clone();
+        for instruction in self.instructions.iter() {
+            part_02::execute_instruction_9001(&mut stacks, *instruction);
+        }
+        top_crates(&stacks)
+    }
+}
+
+pub fn top_crates(stack: &VecDeque<VecDeque<String>>) -> String {
+    let mut res = String::new();
+    for column in stack.iter() {
+        res.extend(column.back().and_then(|top| top.chars().nth(1)));
+    };
+
+    res
+}
+
+/// Every `move` line of the input, checked against `stacks` so that none
+/// moves from or to a stack that isn't there or more crates than a stack
+/// holds. Line numbers in errors count from the top of the input, crate
+/// drawing included.
+pub fn parse_instructions(lines: &[&str], stacks: &VecDeque<VecDeque<String>>) -> Result<Vec<MoveInstruction>, ParseError> {
+    let mut heights = stacks.iter().map(|column| column.len()).collect::<Vec<usize>>();
+
+    lines.iter()
+        .enumerate()
+        .filter(|(_, line)| line.starts_with('m'))
+        .map(|(i, line)| {
+            MoveInstruction::from_line(line.to_string())
+                .and_then(|instruction| instruction.move_heights(line, &mut heights).map(|_| instruction))
+                .map_err(|e| e.at_line(i + 1))
+        })
+        .collect()
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub struct MoveInstruction{
     count: i32, from: i32, to: i32
 }
 impl MoveInstruction{
-    pub fn from_line(input:String) -> Self {
-        let i_vec = input.split(" ").collect::<Vec<&str>>();
-        Self{
-            count: i_vec[1].parse::<i32>().unwrap(),
-            from: i_vec[3].parse::<i32>().unwrap(),
-            to: i_vec[5].parse::<i32>().unwrap()
+    pub fn from_line(input:String) -> Result<Self, ParseError> {
+        let mut tokens = Tokens::new(&input);
+        tokens.keyword("move")?;
+        let count = tokens.number::<i32>("the number of crates to move")?;
+        tokens.keyword("from")?;
+        let from = tokens.number::<i32>("the stack to move from")?;
+        tokens.keyword("to")?;
+        let to = tokens.number::<i32>("the stack to move to")?;
+        tokens.end()?;
+
+        Ok(Self{ count, from, to })
+    }
+
+    /// Moves the crates of this instruction between the stacks `heights`
+    /// counts the crates of, an error pointing into `line` if it can't.
+    fn move_heights(&self, line: &str, heights: &mut [usize]) -> Result<(), ParseError> {
+        let error = |token: usize, expected: String| {
+            let (offset, found) = line
+                .char_indices()
+                .filter(|(i, c)| !c.is_whitespace() && line[..*i].chars().last().is_none_or(char::is_whitespace))
+                .map(|(i, _)| (i, line[i..].split_whitespace().next().unwrap_or("")))
+                .nth(token)
+                .unwrap_or((line.len(), ""));
+            ParseError { text: line.to_string(), ..ParseError::new(line[..offset].chars().count() + 1, expected, found) }
+        };
+        let stack = |n: i32| (n >= 1 && n as usize <= heights.len()).then(|| n as usize - 1);
+        let stacks = format!("a stack from 1 to {}", heights.len());
+
+        let from = stack(self.from).ok_or_else(|| error(3, stacks.clone()))?;
+        let to = stack(self.to).ok_or_else(|| error(5, stacks))?;
+        if self.count < 0 || self.count as usize > heights[from] {
+            return Err(error(1, format!("at most the {} crates on stack {}", heights[from], self.from)));
         }
+
+        heights[from] -= self.count as usize;
+        heights[to] += self.count as usize;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_instructions_01() {
+        let drawing = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\n";
+        let err = AOC_2022_05::new().parse(&format!("{}move 1 from 2 to 1\nmove 4 from 1 to 3", drawing)).unwrap_err();
+        assert_eq!((err.line, err.column, err.expected.as_str()), (7, 6, "at most the 3 crates on stack 1"));
+
+        let err = AOC_2022_05::new().parse(&format!("{}move 1 from 2 to 4", drawing)).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (6, 18, "4"));
+
+        let err = AOC_2022_05::new().parse("[D] [C\n 1   2 \n\nmove 1 from 1 to 2").unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (1, 5, "[C"));
     }
 }
@@ -1,50 +1,34 @@
-use std::time::Instant;
 use std::error::Error;
-use crate::input_reader::read_file_in_cwd;
 use std::collections::VecDeque;
-use crate::MoveInstruction;
+use aoc_input::ParseError;
+use crate::{parse_instructions, top_crates, MoveInstruction};
 
 
 pub fn run() -> Result<(), Box<dyn Error>>{
-    let started = Instant::now();
-    let file = read_file_in_cwd("assets/input.txt");
-    part_01(file);
-    println!("Part 1 completed in: {:.2?}", started.elapsed());
+    let file = aoc_input::read_file("assets/input.txt").unwrap_or_else(aoc_input::exit);
+    let res = part_01(file).unwrap_or_else(aoc_input::exit);
+    println!("Crates on top are: {}", res);
 
     Ok(())
 }
 
-pub fn part_01(input:String){
+pub fn part_01(input:String) -> Result<String, ParseError> {
     let lines:Vec<&str> = input.split("\n").collect();
-    let mut stack = get_initial_stacks(&lines);
-    run_instructions_on_set(&mut stack, &lines);
-    
-    let mut res:String = " ".to_string();
-    for column in stack.iter() {
-        res.push(column[column.len()-1].chars().nth(1).unwrap());
-    };
-
-    println!("Crates on top are: {}", res);
-
-
-}
-
-pub fn run_instructions_on_set(stack: &mut VecDeque<VecDeque<String>>, lines: &Vec<&str>){
-    for line in lines.iter() {
-        if let Some(char) = line.chars().nth(0) {
-            if char == 'm' {
-                let instruction = MoveInstruction::from_line(line.to_string());
-                execute_instruction(stack, instruction);
-            }
-        }
+    let mut stack = get_initial_stacks(&lines)?;
+    for instruction in parse_instructions(&lines, &stack)? {
+        execute_instruction(&mut stack, instruction);
     }
+
+    Ok(top_crates(&stack))
 }
 
-pub fn get_initial_stacks(lines: &Vec<&str>) -> VecDeque<VecDeque<String>>{
+/// The stacks of the crate drawing, bottom crate first. Every crate has to
+/// be drawn like `[A]`.
+pub fn get_initial_stacks(lines: &Vec<&str>) -> Result<VecDeque<VecDeque<String>>, ParseError>{
     let mut stack:VecDeque<VecDeque<String>> = VecDeque::new();
 
     for (index, line) in lines.iter().enumerate() {        
-        match line.chars().nth(0) {
+        match line.chars().next() {
             Some(char) => if char == 'm' { continue },
             None => continue
         }
@@ -57,16 +41,22 @@ pub fn get_initial_stacks(lines: &Vec<&str>) -> VecDeque<VecDeque<String>>{
 
         for (i_cs, crate_spot) in level.iter().enumerate() {
 
-            if index == 0 {
+            if stack.len() <= i_cs {
                 stack.push_back(VecDeque::new());
             }
 
-            if crate_spot.chars().nth(0).unwrap() != ' ' {
-                stack[i_cs].push_front(crate_spot.trim().to_string());
+            if !crate_spot.starts_with(' ') {
+                let drawn = crate_spot.trim();
+                let mut chars = drawn.chars();
+                if !matches!((chars.next(), chars.next(), chars.next(), chars.next()), (Some('['), Some(c), Some(']'), None) if c.is_ascii_alphabetic()) {
+                    let error = ParseError::new(i_cs * 4 + 1, "a crate like '[A]'", drawn).at_line(index + 1);
+                    return Err(ParseError { text: line.to_string(), ..error });
+                }
+                stack[i_cs].push_front(drawn.to_string());
             }
         }        
     }
-    stack
+    Ok(stack)
 }
 
 pub fn execute_instruction(stack: &mut VecDeque<VecDeque<String>>, instruction: MoveInstruction){
@@ -81,7 +71,7 @@ pub fn execute_instruction(stack: &mut VecDeque<VecDeque<String>>, instruction:
 
 
 #[cfg(test)]
-
+#[allow(non_snake_case)]
 mod test {
     use super::*;
 
@@ -93,7 +83,7 @@ mod test {
             "[Z] [M] [P]",
             " 1   2   3 "
         );
-        let output = get_initial_stacks(&input);
+        let output = get_initial_stacks(&input).unwrap();
         
         assert_eq!(output, vec!(
             vec!("[Z]","[N]","[D]"),
@@ -106,7 +96,7 @@ mod test {
     #[test]
     fn MoveInstruction_from_line_01() {
         let input = "move 2 from 7 to 2";
-        let mi = MoveInstruction::from_line(input.to_string());
+        let mi = MoveInstruction::from_line(input.to_string()).unwrap();
 
         assert_eq!(
             mi,
@@ -114,21 +104,30 @@ mod test {
         )
     }
 
+    #[test]
+    fn MoveInstruction_from_line_02() {
+        let mi = MoveInstruction::from_line("move 2 from 7 to 2  ".to_string());
+        assert_eq!(mi, Ok(MoveInstruction{count:2,from:7,to:2}));
+
+        let err = MoveInstruction::from_line("move 2 from x to 2".to_string()).unwrap_err();
+        assert_eq!((err.column, err.expected.as_str(), err.found.as_str()), (13, "the stack to move from", "x"));
+    }
+
     #[test]
     fn execute_instruction_01(){
-        let instruction = MoveInstruction::from_line("move 1 from 1 to 2".to_string());
+        let instruction = MoveInstruction::from_line("move 1 from 1 to 2".to_string()).unwrap();
         let mut original_stack = get_initial_stacks(&vec!(
             "[D]        ",
             "[N] [C]    ",
             "[Z] [M] [P]",
             " 1   2   3 "
-        ));
+        )).unwrap();
         let expected_output_stack = get_initial_stacks(&vec!(
             "    [D]    ",
             "[N] [C]    ",
             "[Z] [M] [P]",
             " 1   2   3 "
-        ));
+        )).unwrap();
 
         execute_instruction(&mut original_stack, instruction);
         assert_eq!(expected_output_stack, original_stack);
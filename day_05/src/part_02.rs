@@ -1,42 +1,25 @@
-use std::time::Instant;
 use std::error::Error;
 use crate::part_01::get_initial_stacks;
-use crate::input_reader::read_file_in_cwd;
-use crate::MoveInstruction;
+use aoc_input::ParseError;
+use crate::{parse_instructions, top_crates, MoveInstruction};
 use std::collections::VecDeque;
 
 pub fn run() -> Result<(), Box<dyn Error>>{
-    let started = Instant::now();
-    let file = read_file_in_cwd("assets/input.txt");
-    part_02(file);
-    println!("Part 2 completed in: {:.2?}", started.elapsed());
+    let file = aoc_input::read_file("assets/input.txt").unwrap_or_else(aoc_input::exit);
+    let res = part_02(file).unwrap_or_else(aoc_input::exit);
+    println!("Crates on top are: {}", res);
 
     Ok(())
 }
 
-pub fn part_02(input:String){
+pub fn part_02(input:String) -> Result<String, ParseError> {
     let lines:Vec<&str> = input.split("\n").collect();
-    let mut stack = get_initial_stacks(&lines);
-    run_instructions_on_set(&mut stack, &lines);
-    
-    let mut res:String = " ".to_string();
-    for column in stack.iter() {
-        res.push(column[column.len()-1].chars().nth(1).unwrap());
-    };
-
-    println!("Crates on top are: {}", res);
-
-}
-
-pub fn run_instructions_on_set(stack: &mut VecDeque<VecDeque<String>>, lines: &Vec<&str>){
-    for line in lines.iter() {
-        if let Some(char) = line.chars().nth(0) {
-            if char == 'm' {
-                let instruction = MoveInstruction::from_line(line.to_string());
-                execute_instruction_9001(stack, instruction);
-            }
-        }
+    let mut stack = get_initial_stacks(&lines)?;
+    for instruction in parse_instructions(&lines, &stack)? {
+        execute_instruction_9001(&mut stack, instruction);
     }
+
+    Ok(top_crates(&stack))
 }
 
 pub fn execute_instruction_9001(stack: &mut VecDeque<VecDeque<String>>, instruction: MoveInstruction){
@@ -1,18 +1,63 @@
-mod input_reader;
 mod part_01;
 mod part_02;
 
 use std::collections::VecDeque;
+use aoc_input::ParseError;
+use aoc_solution::Solution;
 
 pub fn run(){
 
-    let input = input_reader::read_file_in_cwd("assets/input.txt");
+    let input = aoc_input::read_file("assets/input.txt").unwrap_or_else(aoc_input::exit);
 
     part_01::run(input.clone()).unwrap();
-    part_02::run(input.clone()).unwrap();
+    part_02::run(input).unwrap();
     
 }
 
+pub fn part_01(path: Option<&str>) -> i32 {
+    let input = aoc_input::read_file(path.unwrap_or("assets/input.txt")).unwrap_or_else(aoc_input::exit);
+    find_marker(input, 4).0
+}
+
+pub fn part_02(path: Option<&str>) -> i32 {
+    let input = aoc_input::read_file(path.unwrap_or("assets/input.txt")).unwrap_or_else(aoc_input::exit);
+    find_marker(input, 14).0
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Default)]
+pub struct AOC_2022_06 {
+    input: String
+}
+impl AOC_2022_06 {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Solution for AOC_2022_06 {
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn name(&self) -> (usize, usize) {
+        (2022,6)
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+        self.input = input.to_string();
+
+        Ok(())
+    }
+
+    fn part1(&self) -> i32 {
+        find_marker(self.input.clone(), 4).0
+    }
+
+    fn part2(&self) -> i32 {
+        find_marker(self.input.clone(), 14).0
+    }
+}
+
 pub fn find_marker(input:String, unique_seq_count: i32) -> (i32, String) {
     let mut last_four = VecDeque::<char>::new();
 
@@ -46,7 +91,6 @@ pub fn find_marker(input:String, unique_seq_count: i32) -> (i32, String) {
 }
 
 #[cfg(test)]
-
 mod test {
     use super::*;
 
@@ -1,20 +1,70 @@
 #[allow(unused_imports)]
-mod input_reader;
 mod part_01;
 mod part_02;
 
-use std::borrow::BorrowMut;
 use std::collections::HashMap;
-use std::process;
-use std::rc::{self, Rc};
+use std::rc::Rc;
+
+use aoc_input::ParseError;
+use aoc_solution::{Solution, Unsolved};
 
 pub fn run() {
-    let input = input_reader::read_file_in_cwd("assets/input.txt");
+    let input = aoc_input::read_file("assets/input.txt").unwrap_or_else(aoc_input::exit);
 
     part_01::run(input.clone()).unwrap();
     part_02::run(input.clone()).unwrap();
 }
 
+fn build_tree(input: &str) -> Rc<Folder> {
+    let root = Rc::new(Folder::new("/", HashMap::new(), HashMap::new(), None));
+    let mut cwd = root.clone();
+
+    for line in input.lines() {
+        cwd = process_line_part01(line, cwd, &root);
+    }
+
+    cwd.get_root()
+}
+
+/// Only the parsing ever worked here (see day_07_opt for the answers), so the
+/// parts stay unsolved. Kept around to benchmark the parse against day_07_opt.
+#[allow(non_camel_case_types)]
+#[derive(Default)]
+pub struct AOC_2022_07 {
+    root: Rc<Folder>
+}
+impl AOC_2022_07 {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Solution for AOC_2022_07 {
+    type Part1 = Unsolved;
+    type Part2 = Unsolved;
+
+    const PART1_SOLVED: bool = false;
+    const PART2_SOLVED: bool = false;
+
+    fn name(&self) -> (usize, usize) {
+        (2022,7)
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+        self.root = build_tree(input);
+
+        Ok(())
+    }
+
+    fn part1(&self) -> Unsolved {
+        Unsolved
+    }
+
+    fn part2(&self) -> Unsolved {
+        Unsolved
+    }
+}
+
 #[derive(Debug, PartialEq, Clone, Default)]
 struct Folder {
     name: String,
@@ -39,17 +89,18 @@ impl Folder {
 
     pub fn get_size(&self) -> i32 {
         let mut size: i32 = 0;
-        for (_, file) in &self.files {
+        for file in self.files.values() {
             size += file.size
         }
 
-        for (_, folder) in &self.folders {
+        for folder in self.folders.values() {
             size += folder.clone().get_size()
         }
         size
     }
 
-    pub fn tree(&self) -> () {
+    #[allow(dead_code)]
+    pub fn tree(&self) {
         println!("|- {}", self.name);
 
         for (key, file) in self.files.clone() {
@@ -62,13 +113,13 @@ impl Folder {
     }
 
     pub fn get_root(&self) -> Rc<Folder> {
-        if let None = self.parent_folder.clone() {
+        if self.parent_folder.clone().is_none() {
             return Rc::new(self.clone());
         }
 
         let root = &mut self.parent_folder.clone().unwrap();
 
-        while let Some(_) = &root.parent_folder {
+        while root.parent_folder.is_some() {
             *root = root.parent_folder.clone().unwrap();
         }
 
@@ -90,11 +141,11 @@ impl File {
     }
 }
 
-fn process_line_part01<'a>(line: &str, cwd: Rc<Folder>, root: &Rc<Folder>) -> Rc<Folder> {
+fn process_line_part01(line: &str, cwd: Rc<Folder>, root: &Rc<Folder>) -> Rc<Folder> {
     let words = line.split(" ").collect::<Vec<&str>>();
     let cwd = Rc::clone(&cwd);
 
-    if words[0] == "" {
+    if words[0].is_empty() {
         println!("Done!");
 
         // println!("ROOT FOLDER");
@@ -103,7 +154,7 @@ fn process_line_part01<'a>(line: &str, cwd: Rc<Folder>, root: &Rc<Folder>) -> Rc
     }
 
     match (words[0], words[1]) {
-        ("$", "cd") => process_cd(words, &cwd, &root),
+        ("$", "cd") => process_cd(words, &cwd, root),
         ("$", "ls") => cwd,
         ("dir", _) => process_dir(words, &cwd),
         _ => process_file(words, &cwd),
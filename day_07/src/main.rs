@@ -1,6 +1,5 @@
 
 #[allow(unused_imports)]
-
 fn main() {
     day_07::run();    
 }
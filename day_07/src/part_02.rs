@@ -1,10 +1,5 @@
-use std::time::Instant;
 use std::error::Error;
 
-pub fn run(input:String) -> Result<(), Box<dyn Error>>{
-    let started = Instant::now();
-    
-    println!("Part 2 completed in: {:.2?}", started.elapsed());
-
+pub fn run(_input:String) -> Result<(), Box<dyn Error>>{
     Ok(())
-}
\ No newline at end of file
+}
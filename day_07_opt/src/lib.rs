@@ -1,7 +1,9 @@
-use std::{rc::Rc, collections::HashMap, cell::RefCell, f32::INFINITY};
+use std::{rc::Rc, collections::HashMap, cell::RefCell};
+use aoc_input::{ParseError, Tokens};
+use aoc_solution::Solution;
 
-mod input_reader;
 
+#[allow(non_camel_case_types)]
 #[derive(Default)]
 pub struct AOC_2022_07 {
     root: Rc<Dir>
@@ -11,50 +13,67 @@ impl AOC_2022_07 {
     pub fn new() -> Self {
         Self::default()
     }
+}
+
+impl AOC_2022_07 {
+    /// Follows one line of the terminal output, `cwd` being the directory
+    /// the last `cd` went to.
+    fn parse_line(&self, line: &str, cwd: &mut Rc<Dir>) -> Result<(), ParseError> {
+        let mut tokens = Tokens::new(line);
+
+        match tokens.clone().word("'$', 'dir' or a file size")? {
+            "$" => {
+                tokens.keyword("$")?;
+                if tokens.one_of(&["cd", "ls"])? == "cd" {
+                    *cwd = tokens.parse_with("a directory listed before", |dirname| match dirname {
+                        "/" => Some(Rc::clone(&self.root)),
+                        ".." => cwd.parent.clone(),
+                        dirname => cwd.children.borrow().get(dirname).cloned(),
+                    })?;
+                }
+            },
+            "dir" => {
+                tokens.keyword("dir")?;
+                let dirname = tokens.word("a directory name")?;
+                cwd.children.borrow_mut().insert(
+                    dirname.to_string(), 
+                    Rc::new(Dir {
+                        name: dirname.to_string(),
+                        size: RefCell::new(0),
+                        parent: Some(Rc::clone(cwd)),
+                        children: RefCell::new(HashMap::new()),
+                    }),
+                );
+            },
+            _ => {
+                *cwd.size.borrow_mut() += tokens.number::<usize>("'$', 'dir' or a file size")?;
+                tokens.word("a file name")?;
+            }
+        }
+
+        tokens.end()
+    }
+}
 
-    pub fn name(&self) -> (usize, usize) {
+impl Solution for AOC_2022_07 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn name(&self) -> (usize, usize) {
         (2022,7)
     }
 
-    pub fn parse(&mut self) {
-        let lines = input_reader::read_lines();
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
         let mut cwd = Rc::clone(&self.root);
         
-        for line in lines {
-            let words = line.split(" ").collect::<Vec<&str>>();
-
-            match (words[0], words[1]) {
-                ("$", "ls") => {},                
-                ("$", "cd") => {
-                    match words[2] {
-                        "/" => cwd = Rc::clone(&self.root),
-                        ".." => cwd = Rc::clone(&cwd.parent.as_ref().unwrap()),                        
-                        dirname => {
-                            let newdir = cwd.children.borrow().get(dirname).unwrap().clone();
-                            cwd = Rc::clone(&newdir);
-                        }
-                        
-                    }
-                },
-                ("dir", dirname) => { 
-                    cwd.children.borrow_mut().insert(
-                        dirname.to_string(), 
-                        Rc::new(Dir {
-                            name: dirname.to_string(),
-                            size: RefCell::new(0),
-                            parent: Some(Rc::clone(&cwd)),
-                            children: RefCell::new(HashMap::new()),
-                        }),
-                    );
-                },
-                (size, name)=> {
-                    *cwd.size.borrow_mut() += size.parse::<usize>().unwrap();
-                }
-            }
+        for (i, line) in input.lines().enumerate().filter(|(_, l)| !l.trim().is_empty()) {
+            self.parse_line(line, &mut cwd).map_err(|e| e.at_line(i + 1))?;
         }
+
+        Ok(())
     }
 
-    pub fn part1(&mut self) -> usize {
+    fn part1(&self) -> usize {
         let mut to_visit = vec![Rc::clone(&self.root)];
         let mut total = 0;
 
@@ -69,24 +88,21 @@ impl AOC_2022_07 {
             }
         };
 
-        println!("Total size: {}", total);
 
 
 
         total
 
     }
-    pub fn part2(&mut self) -> () {        
+    fn part2(&self) -> usize {        
         let mut to_visit = vec![Rc::clone(&self.root)];
         let mut free_space:usize = 70000000;
         
         for (_, dir) in Rc::clone(&self.root).children.borrow().iter(){
             free_space -= dir.as_ref().get_size();
         }
-        println!("Space currently free: {}", free_space);
 
         let additional_space_needed = 30000000 - free_space;
-        println!("Space that needs to be freed: {}", additional_space_needed);
 
         
         let mut current_folder_size_found:usize = 700000000000;
@@ -105,18 +121,31 @@ impl AOC_2022_07 {
 
             
         };
-        println!("Folder size of smallest folder to be deleted: {}", current_folder_size_found);
 
-        
-
-        
-        
+        current_folder_size_found
     }
 }
 
 
+pub fn part_01(path: Option<&str>) -> usize {
+    let mut runner = AOC_2022_07::new();
+    runner.parse(&aoc_input::read_file(path.unwrap_or("assets/input.txt")).unwrap_or_else(aoc_input::exit)).unwrap_or_else(aoc_input::exit);
+    let total = runner.part1();
+    println!("Total size: {}", total);
+    total
+}
+
+pub fn part_02(path: Option<&str>) -> usize {
+    let mut runner = AOC_2022_07::new();
+    runner.parse(&aoc_input::read_file(path.unwrap_or("assets/input.txt")).unwrap_or_else(aoc_input::exit)).unwrap_or_else(aoc_input::exit);
+    let size = runner.part2();
+    println!("Folder size of smallest folder to be deleted: {}", size);
+    size
+}
+
 #[derive(Default)]
 pub struct Dir {
+    #[allow(dead_code)]
     name: String,
     size: RefCell<usize>,
     parent: Option<Rc<Dir>>,
@@ -130,4 +159,18 @@ impl Dir {
             .values()
             .fold(0, |a,b| a + b.get_size() )
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_01() {
+        let err = AOC_2022_07::new().parse("$ cd /\n$ ls\ndir a\n$ cd b").unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (4, 6, "b"));
+
+        let err = AOC_2022_07::new().parse("$ cd /\n$ ls\n12k a.txt").unwrap_err();
+        assert_eq!((err.line, err.column, err.expected.as_str()), (3, 1, "'$', 'dir' or a file size"));
+    }
+}
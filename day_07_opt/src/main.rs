@@ -1,13 +1,7 @@
-use day_07_opt::AOC_2022_07;
-
-mod input_reader;
-
+use day_07_opt::{part_01, part_02};
 
 fn main() {
-    let mut runner = AOC_2022_07::new();
-    runner.parse();
-    runner.part1();    
-    runner.part2();
+    part_01(None);    
+    part_02(None);
     
-}
-
+}
\ No newline at end of file
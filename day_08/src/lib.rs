@@ -1,17 +1,7 @@
-use std::borrow::BorrowMut;
-use std::time::Instant;
-use std::vec;
-use std::{collections::HashMap, hash::Hash};
-use std::ops::{Range, RangeInclusive};
 use colored::Colorize;
-mod input_reader;
-
-
-#[derive(PartialEq, Debug)]
-enum Direction {
-    column,
-    row
-}
+use aoc_grid::{Point, DIRECTIONS_4};
+use aoc_input::ParseError;
+use aoc_solution::Solution;
 
 
 #[derive(Debug, PartialEq)]
@@ -28,18 +18,16 @@ impl Tree {
     }
 }
 
+#[derive(Default)]
 pub struct Grid{
-    trees: HashMap<(usize, usize), Tree>,
-    max_x: usize,
-    max_y: usize,
+    trees: aoc_grid::Grid<Tree>,
 }
 
 impl Grid {
     pub fn print(&self){
-        for y in 0..=self.max_y {
+        for row in self.trees.rows() {
             let mut line = "".to_string();
-            for x in 0..=self.max_x {
-                let tree = self.trees.get(&(x,y)).unwrap();
+            for tree in row {
                 if tree.visible {
                     line.push_str(
                         format!("{}",tree.height.to_string().as_str().green()).as_str()
@@ -56,44 +44,21 @@ impl Grid {
     }
 
     pub fn trees_visible(&self) -> i32 {
-        let mut vis_count:i32 = 0;
-        for y in 0..=self.max_y {
-            for x in 0..=self.max_x {
-                let tree = self.trees.get(&(x,y)).unwrap();
-                if tree.visible {
-                   vis_count += 1;
-                }
-            }
-        };
-        vis_count
+        self.trees.values().filter(|tree| tree.visible).count() as i32
     }
 
     pub fn get_view_distances_product(&self, x: usize, y: usize) -> usize{
-
-        let mut product = 1;
-
-        let incrementors:Vec<( i32, i32)> = vec![
-            (0,1),
-            (1,0),
-            (0,-1),
-            (-1,0)
-        ];
-
-        for incrementor in incrementors.iter() {
-            product *= self.get_view_distance((x,y), incrementor );
-        };
-
-        product as usize
+        DIRECTIONS_4.iter()
+            .map(|direction| self.get_view_distance((x as isize, y as isize), *direction) as usize)
+            .product()
     }
 
-    pub fn get_view_distance(&self, start: (usize, usize), incrementor: &(i32, i32)) -> i32{
-        let mut current_position = (start.0 as i32 + incrementor.0, start.1 as i32 + incrementor.1);
-        let my_tree = self.trees.get(&start).unwrap();
+    pub fn get_view_distance(&self, start: Point, direction: Point) -> i32{
+        let my_tree = &self.trees[start];
         let mut view_distance = 0;
 
-        while let Some(tree) = self.trees.get(&(current_position.0 as usize, current_position.1 as usize)) {
+        for (_, tree) in self.trees.ray(start, direction) {
             view_distance += 1;
-            current_position = (current_position.0 + incrementor.0, current_position.1 + incrementor.1);
             if tree.height >= my_tree.height {
                 break;
             }
@@ -103,116 +68,91 @@ impl Grid {
     }
 }
 
-pub fn part_01(){
-    let input = input_reader::read_file_in_cwd("assets/input.txt");
-    let started = Instant::now();
-    let mut grid = parse_grid(&input);
+pub fn part_01(path: Option<&str>) -> i32 {
+    let input = aoc_input::read_file(path.unwrap_or("assets/input.txt")).unwrap_or_else(aoc_input::exit);
+    let mut grid = parse_grid(&input).unwrap_or_else(aoc_input::exit);
     
     calc_visibility(&mut grid);
-    println!("Trees visible: {}", grid.trees_visible());
-    println!("Execution took {:?}", Instant::now()-started);
+    let trees_visible = grid.trees_visible();
+    println!("Trees visible: {}", trees_visible);
     grid.print();
+
+    trees_visible
 }
 
-pub fn part_02(){
-    let input = input_reader::read_file_in_cwd("assets/input.txt");
-    let started = Instant::now();
+pub fn part_02(path: Option<&str>) -> usize {
+    let input = aoc_input::read_file(path.unwrap_or("assets/input.txt")).unwrap_or_else(aoc_input::exit);
 
-    let grid = parse_grid(&input);
-    let mut max_view_distance_score = 0;
-    for (coords,_) in &grid.trees {
-        let current_viewing_score = grid.get_view_distances_product(coords.0, coords.1);
-        if current_viewing_score > max_view_distance_score {
-            max_view_distance_score = current_viewing_score;
-        }
-    }
+    let grid = parse_grid(&input).unwrap_or_else(aoc_input::exit);
+    let max_view_distance_score = max_view_distances_product(&grid);
 
     println!("Max viewing score was: {}", max_view_distance_score);
 
-    println!("Execution tool {:?}", Instant::now() - started);
+    max_view_distance_score
 }
 
-pub fn parse_grid(input:&str) -> Grid{
-    let mut hm: HashMap<(usize,usize), Tree> = HashMap::new();
-    let mut max_x = 0;
-    let mut max_y = 0;
-
-    let lines = input.split("\n").collect::<Vec<&str>>();
-
-    for (y, line) in lines.iter().enumerate() {
-        max_y = y;
-        for (x, c) in line.chars().enumerate() {
-            max_x = x;
-            hm.insert(
-                (x,y), 
-                Tree::new(c.to_string().parse::<i32>().unwrap())
-            );
+pub fn max_view_distances_product(grid: &Grid) -> usize {
+    let mut max_view_distance_score = 0;
+    for (x, y) in grid.trees.points() {
+        let current_viewing_score = grid.get_view_distances_product(x as usize, y as usize);
+        if current_viewing_score > max_view_distance_score {
+            max_view_distance_score = current_viewing_score;
         }
-    };
-
+    }
+    max_view_distance_score
+}
 
-    Grid{
-        max_x,
-        max_y,
-        trees: hm,
+#[allow(non_camel_case_types)]
+#[derive(Default)]
+pub struct AOC_2022_08 {
+    grid: Grid
+}
+impl AOC_2022_08 {
+    pub fn new() -> Self {
+        Self::default()
     }
 }
 
-pub fn calc_visibility(grid: &mut Grid){
-    let max_x = grid.max_x;
-    let max_y = grid.max_y;
+impl Solution for AOC_2022_08 {
+    type Part1 = i32;
+    type Part2 = usize;
 
-    
-    let range_combinations = vec![
-        ((0..=max_x).collect::<Vec<usize>>(), (0..=max_y).collect::<Vec<usize>>(), Direction::row),
-        ((0..=max_x).rev().collect::<Vec<usize>>(), (0..=max_y).collect::<Vec<usize>>(), Direction::row),
-        ((0..=max_x).collect::<Vec<usize>>(), (0..=max_y).collect::<Vec<usize>>(), Direction::column),
-        ((0..=max_x).collect::<Vec<usize>>(), (0..=max_y).rev().collect::<Vec<usize>>(), Direction::column)
-    ];
+    fn name(&self) -> (usize, usize) {
+        (2022,8)
+    }
 
-    
-    
-    for range_combination in range_combinations {
-        let outer_range:Vec<usize>;
-        let inner_range:Vec<usize>;
-
-        if range_combination.2 == Direction::row {
-            outer_range = range_combination.1;
-            inner_range = range_combination.0;
-        } else {
-            outer_range = range_combination.0;
-            inner_range = range_combination.1;
-        };
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+        self.grid = parse_grid(input)?;
+        calc_visibility(&mut self.grid);
 
-        for i in outer_range.clone() {
-            let mut line_of_sight:i32 = -1;
+        Ok(())
+    }
 
-            for j in inner_range.clone() {
+    fn part1(&self) -> i32 {
+        self.grid.trees_visible()
+    }
+
+    fn part2(&self) -> usize {
+        max_view_distances_product(&self.grid)
+    }
+}
 
-                let x:usize;
-                let y:usize;
+pub fn parse_grid(input:&str) -> Result<Grid, ParseError>{
+    let trees = aoc_grid::Grid::parse(input, "a tree height", |c| c.to_digit(10).map(|height| Tree::new(height as i32)))?;
 
-                if range_combination.2 == Direction::row {
-                    x = j;
-                    y = i;    
-                } else {
-                    x = i;
-                    y = j;
-                }
+    Ok(Grid{
+        trees,
+    })
+}
 
-                
-                let tree = grid.trees.get_mut(&(x,y)).unwrap();
+/// A tree is visible if every tree towards one of the edges is smaller.
+pub fn calc_visibility(grid: &mut Grid){
+    for point in grid.trees.points().collect::<Vec<Point>>() {
+        let height = grid.trees[point].height;
+        let visible = DIRECTIONS_4.iter()
+            .any(|direction| grid.trees.ray(point, *direction).all(|(_, tree)| tree.height < height));
 
-                if tree.height > line_of_sight {
-                    tree.borrow_mut().visible = true;                    
-                    line_of_sight = tree.height;
-                } else {
-                    if line_of_sight == 9 {
-                        break;
-                    }
-                }
-            }
-        }
+        grid.trees[point].visible = visible;
     }
 }
 
@@ -222,48 +162,52 @@ mod test {
 
     #[test]
     fn parse_grid_01(){
-        let input = input_reader::read_file_in_cwd("assets/test_input_02.txt");
-        let grid  = parse_grid(&input);
-        let mut expected:HashMap<(usize,usize), Tree> = HashMap::new();
-        expected.insert((0,0), Tree::new(1));
-        expected.insert((1,0), Tree::new(1));
-        expected.insert((2,0), Tree::new(1));
-        expected.insert((0,1), Tree::new(1));
-        expected.insert((1,1), Tree::new(1));
-        expected.insert((2,1), Tree::new(2));
-        expected.insert((0,2), Tree::new(0));
-        expected.insert((1,2), Tree::new(1));
-        expected.insert((2,2), Tree::new(0));
-
-        assert_eq!(grid.trees, expected);
+        let input = aoc_input::read_file("assets/test_input_02.txt").unwrap();
+        let grid  = parse_grid(&input).unwrap();
+        let expected = vec![
+            Tree::new(1),
+            Tree::new(1),
+            Tree::new(1),
+            Tree::new(1),
+            Tree::new(1),
+            Tree::new(2),
+            Tree::new(0),
+            Tree::new(1),
+            Tree::new(0),
+        ];
 
+        assert_eq!(grid.trees, aoc_grid::Grid::from_vec(3, 3, expected));
+
+        let Err(err) = parse_grid("12\n3x\n") else { panic!("3x is no row of trees") };
+        assert_eq!((err.line, err.column), (2, 2));
     }
 
     #[test]
     fn calc_visibility_01(){
-        let input = input_reader::read_file_in_cwd("assets/test_input_02.txt");
-        let mut grid = parse_grid(&input);
+        let input = aoc_input::read_file("assets/test_input_02.txt").unwrap();
+        let mut grid = parse_grid(&input).unwrap();
         
         calc_visibility(&mut grid);
-        let mut expected:HashMap<(usize,usize), Tree> = HashMap::new();
-        expected.insert((0,0), Tree{height: 1, visible: true});
-        expected.insert((1,0), Tree{height: 1, visible: true});
-        expected.insert((2,0), Tree{height: 1, visible: true});
-        expected.insert((0,1), Tree{height: 1, visible: true});
-        expected.insert((1,1), Tree{height: 1, visible: false});
-        expected.insert((2,1), Tree{height: 2, visible: true});
-        expected.insert((0,2), Tree{height: 0, visible: true});
-        expected.insert((1,2), Tree{height: 1, visible: true});
-        expected.insert((2,2), Tree{height: 0, visible: true});
+        let expected = vec![
+            Tree{height: 1, visible: true},
+            Tree{height: 1, visible: true},
+            Tree{height: 1, visible: true},
+            Tree{height: 1, visible: true},
+            Tree{height: 1, visible: false},
+            Tree{height: 2, visible: true},
+            Tree{height: 0, visible: true},
+            Tree{height: 1, visible: true},
+            Tree{height: 0, visible: true},
+        ];
 
         println!("Trees visible: {}", grid.trees_visible());
 
-        assert_eq!(grid.trees, expected);
+        assert_eq!(grid.trees, aoc_grid::Grid::from_vec(3, 3, expected));
     }
     #[test]
     fn calc_visibility_02(){
-        let input = input_reader::read_file_in_cwd("assets/test_input_01.txt");
-        let mut grid = parse_grid(&input);
+        let input = aoc_input::read_file("assets/test_input_01.txt").unwrap();
+        let mut grid = parse_grid(&input).unwrap();
         
         
         calc_visibility(&mut grid);
@@ -274,12 +218,21 @@ mod test {
 
     #[test]
     fn get_view_distances_product_01(){
-        let input = input_reader::read_file_in_cwd("assets/test_input_01.txt");
-        let grid = parse_grid(&input);
+        let input = aoc_input::read_file("assets/test_input_01.txt").unwrap();
+        let grid = parse_grid(&input).unwrap();
         
         
         let expected = grid.get_view_distances_product(2, 3);        
 
-        assert_eq!(8 as usize, expected);
+        assert_eq!(8_usize, expected);
+    }
+
+    #[test]
+    fn solution_01(){
+        let mut solution = AOC_2022_08::new();
+        solution.parse(&aoc_input::read_file("assets/test_input_01.txt").unwrap()).unwrap();
+
+        assert_eq!(solution.part1(), 21);
+        assert_eq!(solution.part2(), 8);
     }
 }
\ No newline at end of file
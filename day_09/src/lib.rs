@@ -1,32 +1,68 @@
-mod input_reader;
-use std::{collections::HashMap, time::Instant };
+use std::collections::HashMap;
+use aoc_input::{ParseError, Tokens};
+use aoc_solution::Solution;
 
-pub fn part_01(){
-    let started = Instant::now();
-    let lines = input_reader::read_lines("assets/input.txt");
-    let mut rope = Rope::new(1);
+pub fn part_01(path: Option<&str>) -> usize {
+    let input = aoc_input::read_file(path.unwrap_or("assets/input.txt")).unwrap_or_else(aoc_input::exit);
+    let movements = aoc_input::parse_lines(&input, line_to_movement).unwrap_or_else(aoc_input::exit);
+    let visited = positions_visited_by_tail(&movements, 1);
 
-    for line in lines {
-        let movement = line_to_movement(&line);
-        rope.move_head(movement);
+    println!("Part 1 - Positions visited by tail: {}", visited);
+
+    visited
+}
+
+pub fn part_02(path: Option<&str>) -> usize {
+    let input = aoc_input::read_file(path.unwrap_or("assets/input.txt")).unwrap_or_else(aoc_input::exit);
+    let movements = aoc_input::parse_lines(&input, line_to_movement).unwrap_or_else(aoc_input::exit);
+    let visited = positions_visited_by_tail(&movements, 9);
+
+    println!("Part 2 - Positions visited by tail: {}", visited);
+
+    visited
+}
+
+pub fn positions_visited_by_tail(movements: &[(Direction, i32)], tail_length: i32) -> usize {
+    let mut rope = Rope::new(tail_length);
+
+    for movement in movements {
+        rope.move_head(*movement);
     }
 
-    println!("Part 1 - Positions visited by tail: {}", rope.positions_visited_by_tail.len());
-    println!("Execution time: {:?}", Instant::now() - started);
+    rope.positions_visited_by_tail.len()
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Default)]
+pub struct AOC_2022_09 {
+    movements: Vec<(Direction, i32)>
+}
+impl AOC_2022_09 {
+    pub fn new() -> Self {
+        Self::default()
+    }
 }
 
-pub fn part_02(){
-    let started = Instant::now();
-    let lines = input_reader::read_lines("assets/input.txt");
-    let mut rope = Rope::new(9);
+impl Solution for AOC_2022_09 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn name(&self) -> (usize, usize) {
+        (2022,9)
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+        self.movements = aoc_input::parse_lines(input, line_to_movement)?;
+        Ok(())
+    }
 
-    for line in lines {
-        let movement = line_to_movement(&line);
-        rope.move_head(movement);
+    fn part1(&self) -> usize {
+        positions_visited_by_tail(&self.movements, 1)
     }
 
-    println!("Part 2 - Positions visited by tail: {}", rope.positions_visited_by_tail.len());
-    println!("Execution time: {:?}", Instant::now() - started);
+    fn part2(&self) -> usize {
+        positions_visited_by_tail(&self.movements, 9)
+    }
 }
 
 #[derive(PartialEq, Debug, Copy, Clone)]
@@ -62,7 +98,7 @@ impl Rope {
 
     }
 
-    pub fn move_head(&mut self, steps: (Direction, i32)) -> (){       
+    pub fn move_head(&mut self, steps: (Direction, i32)){       
 
         for _ in 0..steps.1 {
             match steps.0 {
@@ -77,11 +113,11 @@ impl Rope {
 
     }
 
-    pub fn move_knots(&mut self) -> (){
+    pub fn move_knots(&mut self){
         let knot_len = &self.knots.len();
         let mut parent = self.head;
         for (index, knot) in self.knots.iter_mut().enumerate() {
-            *knot = move_knot(&knot, &parent);
+            *knot = move_knot(knot, &parent);
             parent = *knot;
 
             if index == *knot_len-1 {
@@ -93,17 +129,18 @@ impl Rope {
 
 }
 
-pub fn line_to_movement(line:&str)-> (Direction, i32) {
-    let chars = line.split(" ").collect::<Vec<&str>>();
-    let steps = chars[1].to_string().parse::<i32>().unwrap();
+pub fn line_to_movement(line:&str)-> Result<(Direction, i32), ParseError> {
+    let mut tokens = Tokens::new(line);
+    let direction = match tokens.one_of(&["R", "L", "U", "D"])? {
+        "R" => Direction::R,
+        "L" => Direction::L,
+        "U" => Direction::U,
+        _ => Direction::D,
+    };
+    let steps = tokens.number::<i32>("a number of steps")?;
+    tokens.end()?;
 
-    match chars[0] {
-        "R" => (Direction::R, steps),
-        "L" => (Direction::L, steps),
-        "U" => (Direction::U, steps),
-        "D" => (Direction::D, steps),
-        _ => panic!("{} is not a known direction", chars[0])
-    }
+    Ok((direction, steps))
 }
 
 pub fn knot_detached(current_knot: &(i32,i32), previous_knot:&(i32,i32)) -> bool {
@@ -135,31 +172,34 @@ mod test {
 
     #[test]
     fn line_to_movement_01(){
-        let commands = vec![
-            "R 2",
+        let commands = ["R 2",
             "U 39",
             "D 1",
-            "L 6"
-        ];
-        let correct_movements = vec![
-            (Direction::R,2),
+            "L 6"];
+        let correct_movements = [(Direction::R,2),
             (Direction::U,39),
             (Direction::D,1),
-            (Direction::L,6)
-        ];
+            (Direction::L,6)];
 
         for (index, command) in commands.iter().enumerate() {
-            assert_eq!(correct_movements[index], line_to_movement(command));
+            assert_eq!(Ok(correct_movements[index]), line_to_movement(command));
         }
     }
 
+    #[test]
+    fn line_to_movement_02(){
+        assert_eq!(line_to_movement("R 2 "), Ok((Direction::R, 2)));
+        assert_eq!(line_to_movement("R").unwrap_err().expected, "a number of steps");
+        assert_eq!(line_to_movement("X 2").unwrap_err().column, 1);
+    }
+
     #[test]
     fn rope_01(){
-        let lines = input_reader::read_lines("assets/input_test_01.txt");
+        let lines = aoc_input::read_lines("assets/input_test_01.txt").unwrap();
         let mut rope = Rope::new(1);
 
         for line in lines {
-            let movement = line_to_movement(&line);
+            let movement = line_to_movement(&line).unwrap();
             rope.move_head(movement);
         }
 
@@ -1,6 +1,6 @@
 use day_09::{part_01, part_02};
 
 fn main() {
-    part_01();
-    part_02();
+    part_01(None);
+    part_02(None);
 }
@@ -0,0 +1,162 @@
+use aoc_grid::Grid;
+
+use crate::Instruction;
+
+/// The program that draws `pixels` on the puzzle's CRT, a sprite 3 pixels
+/// wide and the beam moving on to the next row after `pixels.width()`,
+/// taking exactly a cycle per pixel. `None` if no program can draw it,
+/// like any image without the first two pixels lit: `X` starts at 1 and
+/// nothing can change it before the second cycle is over. Of the letters of
+/// the font only B, E, F, P, R and Z can start a text that way.
+///
+/// `X` only changes when an `addx` finishes, so it holds each value for at
+/// least the two cycles the `addx` takes, noops filling up the rest. Which
+/// value to hold during every cycle is worked out cycle by cycle, keeping
+/// for each value the way to get there with the fewest `addx`, which takes
+/// time and memory linear in the pixels times the width.
+pub fn assemble(pixels: &Grid<bool>) -> Option<Vec<Instruction>> {
+    let width = pixels.width() as i32;
+    let cycles = pixels.width() * pixels.height();
+    if cycles == 0 {
+        return Some(vec![]);
+    }
+
+    // -2 and width + 1 light nothing, so those are all the values needed
+    let values = (-2..=width + 1).collect::<Vec<i32>>();
+    let draws = |cycle: usize, x_value: i32| {
+        let x = (cycle % pixels.width()) as i32;
+        ((x_value - x).abs() <= 1) == pixels[(x as isize, (cycle / pixels.width()) as isize)]
+    };
+
+    // best[cycle][value][held] is the fewest changes of X to have `value`
+    // during `cycle`, `held` if it has been that since the cycle before,
+    // along with what X was the cycle before
+    let mut best = vec![vec![[None::<(usize, usize, bool)>; 2]; values.len()]; cycles];
+    let start = values.iter().position(|v| *v == 1).unwrap();
+    if draws(0, 1) {
+        best[0][start][0] = Some((0, start, false));
+    }
+
+    for cycle in 1..cycles {
+        // X can only change to a value it hasn't held since at least the
+        // cycle before, so the two cheapest of those are all it can come from
+        let mut cheapest = [None::<(usize, usize)>; 2];
+        for (from, [_, held]) in best[cycle - 1].iter().enumerate() {
+            let Some((changes, _, _)) = *held else {
+                continue;
+            };
+            if cheapest[0].is_none_or(|(c, _)| changes < c) {
+                cheapest = [Some((changes, from)), cheapest[0]];
+            } else if cheapest[1].is_none_or(|(c, _)| changes < c) {
+                cheapest[1] = Some((changes, from));
+            }
+        }
+
+        for to in 0..values.len() {
+            if !draws(cycle, values[to]) {
+                continue;
+            }
+
+            best[cycle][to][1] = [false, true]
+                .into_iter()
+                .filter_map(|held| best[cycle - 1][to][held as usize].map(|(changes, _, _)| (changes, to, held)))
+                .min();
+            best[cycle][to][0] = cheapest
+                .into_iter()
+                .flatten()
+                .find(|(_, from)| *from != to)
+                .map(|(changes, from)| (changes + 1, from, true));
+        }
+    }
+
+    let (mut value, mut held) = (0..values.len())
+        .flat_map(|v| [(v, false), (v, true)])
+        .filter_map(|(v, h)| best[cycles - 1][v][h as usize].map(|(changes, _, _)| (changes, v, h)))
+        .min()
+        .map(|(_, v, h)| (v, h))?;
+
+    let mut x_values = vec![0; cycles];
+    for cycle in (0..cycles).rev() {
+        x_values[cycle] = values[value];
+        let (_, from, was_held) = best[cycle][value][held as usize].unwrap();
+        (value, held) = (from, was_held);
+    }
+
+    Some(program_for(&x_values))
+}
+
+/// Holds `X` at each of `x_values` for a cycle, which must keep every
+/// value but the last for at least two cycles.
+fn program_for(x_values: &[i32]) -> Vec<Instruction> {
+    let mut program = vec![];
+    let mut segment_start = 0;
+
+    for cycle in 1..=x_values.len() {
+        let next = x_values.get(cycle);
+        if next == Some(&x_values[segment_start]) {
+            continue;
+        }
+
+        let length = cycle - segment_start;
+        match next {
+            Some(next) => {
+                program.extend((0..length - 2).map(|_| Instruction::noop()));
+                program.push(Instruction::addx(next - x_values[segment_start]));
+            },
+            None => program.extend((0..length).map(|_| Instruction::noop())),
+        }
+        segment_start = cycle;
+    }
+
+    program
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{ocr, parse_program, Screen, CPU};
+
+    fn draw(program: &[Instruction], dimensions: (i32, i32)) -> Screen {
+        let mut screen = Screen::from_cpu(CPU::from_program(program), dimensions);
+        screen.load_pixels();
+        screen
+    }
+
+    #[test]
+    fn program_for_01() {
+        let program = program_for(&[1, 1, 1, 5, 5, -2, -2, -2]);
+
+        assert_eq!(
+            program.iter().map(|i| i.to_string()).collect::<Vec<String>>(),
+            vec!["noop", "addx 4", "addx -7", "noop", "noop", "noop"]
+        );
+    }
+
+    #[test]
+    fn assemble_01() {
+        let pixels = ocr::encode("EPJBRKAH", 40).unwrap();
+        let program = assemble(&pixels).unwrap();
+        let screen = draw(&program, (40, 6));
+
+        assert_eq!(screen.decode(), "EPJBRKAH");
+        assert_eq!(screen.pixels, pixels);
+
+        let input = aoc_input::read_file("assets/input.txt").unwrap();
+        let puzzle = draw(&parse_program(&input).unwrap(), (40, 6));
+        assert_eq!(draw(&assemble(&puzzle.pixels).unwrap(), (40, 6)).pixels, puzzle.pixels);
+    }
+
+    #[test]
+    fn assemble_02() {
+        let example = draw(&parse_program(&aoc_input::read_file("assets/input_test_02.txt").unwrap()).unwrap(), (40, 6));
+        assert_eq!(draw(&assemble(&example.pixels).unwrap(), (40, 6)).render(), example.render());
+
+        for letters in ["BACEGHIJ", "FKLOPSUZ", "ZZ"] {
+            let pixels = ocr::encode(letters, 40).unwrap();
+            assert_eq!(draw(&assemble(&pixels).unwrap(), (40, 6)).pixels, pixels);
+        }
+
+        assert_eq!(assemble(&Grid::from_vec(4, 1, vec![true, false, true, true])), None);
+        assert_eq!(assemble(&ocr::encode("HI", 40).unwrap()), None);
+    }
+}
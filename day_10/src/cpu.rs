@@ -0,0 +1,337 @@
+use std::collections::VecDeque;
+use std::fmt;
+
+use aoc_input::{ParseError, Tokens};
+
+/// Registers are named by a capital letter, `X` is the one the puzzle's
+/// programs work with. It starts at 1, all others at 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Registers([i32; 26]);
+
+impl Default for Registers {
+    fn default() -> Self {
+        let mut registers = Self([0; 26]);
+        registers.set('X', 1);
+        registers
+    }
+}
+
+impl Registers {
+    fn index(name: char) -> usize {
+        assert!(name.is_ascii_uppercase(), "no register named {:?}", name);
+        (name as u8 - b'A') as usize
+    }
+
+    pub fn get(&self, name: char) -> i32 {
+        self.0[Self::index(name)]
+    }
+
+    pub fn set(&mut self, name: char, value: i32) {
+        self.0[Self::index(name)] = value;
+    }
+
+    /// What `operand` stands for, a register's current value or a number.
+    pub fn value(&self, operand: &Operand) -> i32 {
+        match operand {
+            Operand::Value(value) => *value,
+            Operand::Register(name) => self.get(*name),
+        }
+    }
+}
+
+/// What an instruction takes, the text is what a parse error says was
+/// expected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperandKind {
+    Value(&'static str),
+    Register(&'static str),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    Value(i32),
+    Register(char),
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Value(value) => write!(f, "{}", value),
+            Operand::Register(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+/// One row of an instruction set: what an instruction is called, what it
+/// takes, how many cycles it keeps the CPU busy and what it does to the
+/// registers once its last cycle is over.
+#[derive(Debug, Clone, Copy)]
+pub struct Definition {
+    pub mnemonic: &'static str,
+    pub operands: &'static [OperandKind],
+    pub cycles: u32,
+    pub effect: fn(&mut Registers, &[Operand]),
+}
+
+pub const NOOP: Definition = Definition {
+    mnemonic: "noop",
+    operands: &[],
+    cycles: 1,
+    effect: |_, _| (),
+};
+
+pub const ADDX: Definition = Definition {
+    mnemonic: "addx",
+    operands: &[OperandKind::Value("a value to add")],
+    cycles: 2,
+    effect: |registers, operands| registers.set('X', registers.get('X') + registers.value(&operands[0])),
+};
+
+#[derive(Debug, Clone)]
+pub struct Instruction {
+    pub definition: Definition,
+    pub operands: Vec<Operand>,
+}
+
+impl Instruction {
+    pub fn noop() -> Self {
+        Self { definition: NOOP, operands: vec![] }
+    }
+
+    pub fn addx(value: i32) -> Self {
+        Self { definition: ADDX, operands: vec![Operand::Value(value)] }
+    }
+
+    pub fn mnemonic(&self) -> &'static str {
+        self.definition.mnemonic
+    }
+}
+
+impl PartialEq for Instruction {
+    fn eq(&self, other: &Self) -> bool {
+        self.mnemonic() == other.mnemonic() && self.operands == other.operands
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.mnemonic())?;
+        for operand in &self.operands {
+            write!(f, " {}", operand)?;
+        }
+        Ok(())
+    }
+}
+
+/// The instructions a program may use, looked up by mnemonic.
+#[derive(Debug, Clone)]
+pub struct InstructionSet {
+    definitions: Vec<Definition>,
+}
+
+impl Default for InstructionSet {
+    /// `noop` and `addx`, all the puzzle needs.
+    fn default() -> Self {
+        Self { definitions: vec![NOOP, ADDX] }
+    }
+}
+
+impl InstructionSet {
+    /// Adds `definition`, replacing one with the same mnemonic.
+    pub fn with(mut self, definition: Definition) -> Self {
+        self.definitions.retain(|d| d.mnemonic != definition.mnemonic);
+        self.definitions.push(definition);
+        self
+    }
+
+    pub fn get(&self, mnemonic: &str) -> Option<&Definition> {
+        self.definitions.iter().find(|d| d.mnemonic == mnemonic)
+    }
+
+    pub fn parse_program(&self, input: &str) -> Result<Vec<Instruction>, ParseError> {
+        aoc_input::parse_lines(input, |line| self.parse_instruction(line))
+    }
+
+    pub fn parse_instruction(&self, line: &str) -> Result<Instruction, ParseError> {
+        let mut tokens = Tokens::new(line);
+        let mnemonics = self.definitions.iter().map(|d| d.mnemonic).collect::<Vec<&str>>();
+        let definition = *self.get(tokens.one_of(&mnemonics)?).unwrap();
+
+        let operands = definition
+            .operands
+            .iter()
+            .map(|kind| match kind {
+                OperandKind::Value(expected) => tokens.number(expected).map(Operand::Value),
+                OperandKind::Register(expected) => tokens.parse_with(expected, |token| {
+                    let mut chars = token.chars();
+                    match (chars.next(), chars.next()) {
+                        (Some(name), None) if name.is_ascii_uppercase() => Some(Operand::Register(name)),
+                        _ => None,
+                    }
+                }),
+            })
+            .collect::<Result<Vec<Operand>, ParseError>>()?;
+        tokens.end()?;
+
+        Ok(Instruction { definition, operands })
+    }
+}
+
+/// Watches the CPU work. Called once per cycle while the cycle is in
+/// progress, numbered from 1, so the registers still hold what they held
+/// when it started.
+pub trait Observer {
+    fn observe(&mut self, cycle: i32, cpu: &CPU);
+
+    /// Called once `cycle` is over and the instruction finishing in it has
+    /// taken effect.
+    fn after(&mut self, _cycle: i32, _cpu: &CPU) {}
+}
+
+impl<F: FnMut(i32, &CPU)> Observer for F {
+    fn observe(&mut self, cycle: i32, cpu: &CPU) {
+        self(cycle, cpu)
+    }
+}
+
+pub struct CPU {
+    instructions: VecDeque<Instruction>,
+    cycle: i32,
+    registers: Registers,
+    current_instruction: Option<Instruction>,
+    ticks_to_completion: u32,
+}
+
+impl CPU {
+    pub fn new(input_file: &str) -> Self {
+        let input = aoc_input::read_file(input_file).unwrap_or_else(aoc_input::exit);
+        let program = InstructionSet::default().parse_program(&input).unwrap_or_else(aoc_input::exit);
+        Self::from_program(&program)
+    }
+
+    pub fn from_program(program: &[Instruction]) -> Self {
+        let mut init_state = Self {
+            current_instruction: None,
+            instructions: program.iter().cloned().collect(),
+            cycle: 0,
+            registers: Registers::default(),
+            ticks_to_completion: 0,
+        };
+
+        init_state.load_next_instruction();
+        init_state
+    }
+
+    /// How many cycles have completed.
+    pub fn cycle(&self) -> i32 {
+        self.cycle
+    }
+
+    pub fn registers(&self) -> &Registers {
+        &self.registers
+    }
+
+    pub fn register(&self, name: char) -> i32 {
+        self.registers.get(name)
+    }
+
+    pub fn current_instruction(&self) -> Option<&Instruction> {
+        self.current_instruction.as_ref()
+    }
+
+    /// The cycles the current instruction still needs, this one included.
+    pub fn ticks_to_completion(&self) -> u32 {
+        self.ticks_to_completion
+    }
+
+    /// The instructions after the current one.
+    pub fn pending(&self) -> impl Iterator<Item = &Instruction> {
+        self.instructions.iter()
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.current_instruction.is_none()
+    }
+
+    pub fn signal_strengh(&self) -> i32 {
+        self.cycle * self.register('X')
+    }
+
+    pub fn tick(&mut self) {
+        self.tick_with(&mut |_, _: &CPU| ())
+    }
+
+    /// Runs one cycle, letting `observer` look at it first. Once the program
+    /// is done cycles still pass, they just don't do anything.
+    pub fn tick_with(&mut self, observer: &mut impl Observer) {
+        observer.observe(self.cycle + 1, self);
+
+        self.cycle += 1;
+        if let Some(instruction) = &self.current_instruction {
+            self.ticks_to_completion -= 1;
+            if self.ticks_to_completion == 0 {
+                (instruction.definition.effect)(&mut self.registers, &instruction.operands);
+                self.load_next_instruction();
+            }
+        }
+
+        observer.after(self.cycle, self);
+    }
+
+    fn load_next_instruction(&mut self) {
+        self.current_instruction = self.instructions.pop_front();
+        self.ticks_to_completion = self.current_instruction.as_ref().map_or(0, |i| i.definition.cycles.max(1));
+    }
+
+    pub fn run(&mut self) {
+        self.run_with(&mut |_, _: &CPU| ())
+    }
+
+    pub fn run_with(&mut self, observer: &mut impl Observer) {
+        while !self.is_done() {
+            self.tick_with(observer)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SETR: Definition = Definition {
+        mnemonic: "setr",
+        operands: &[OperandKind::Register("a register"), OperandKind::Value("a value")],
+        cycles: 1,
+        effect: |registers, operands| match operands[0] {
+            Operand::Register(name) => registers.set(name, registers.value(&operands[1])),
+            Operand::Value(_) => unreachable!(),
+        },
+    };
+
+    const MULX: Definition = Definition {
+        mnemonic: "mulx",
+        operands: &[OperandKind::Register("a register")],
+        cycles: 3,
+        effect: |registers, operands| registers.set('X', registers.get('X') * registers.value(&operands[0])),
+    };
+
+    #[test]
+    fn instruction_set_01() {
+        let set = InstructionSet::default().with(SETR).with(MULX);
+        let program = set.parse_program("setr Y 7\nmulx Y\naddx -2\n").unwrap();
+
+        assert_eq!(program[1], Instruction { definition: MULX, operands: vec![Operand::Register('Y')] });
+        assert_eq!(program.iter().map(|i| i.to_string()).collect::<Vec<_>>(), vec!["setr Y 7", "mulx Y", "addx -2"]);
+
+        let mut cpu = CPU::from_program(&program);
+        let mut seen = vec![];
+        cpu.run_with(&mut |cycle, cpu: &CPU| seen.push((cycle, cpu.register('X'), cpu.register('Y'))));
+
+        assert_eq!(seen, vec![(1, 1, 0), (2, 1, 7), (3, 1, 7), (4, 1, 7), (5, 7, 7), (6, 7, 7)]);
+        assert_eq!((cpu.cycle(), cpu.register('X')), (6, 5));
+
+        let err = set.parse_program("setr y 7").unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (6, "a register"));
+        assert!(InstructionSet::default().parse_program("mulx Y").is_err());
+    }
+}
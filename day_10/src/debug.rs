@@ -0,0 +1,321 @@
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+
+use crate::{Observer, CPU};
+
+/// Writes a line per cycle: its number, the instruction running, the
+/// cycles it still needs counting this one and `X` during and after the
+/// cycle.
+pub struct Trace<W: Write> {
+    out: W,
+    during: Option<(String, u32, i32)>,
+    result: io::Result<()>,
+}
+
+impl<W: Write> Trace<W> {
+    pub fn new(out: W) -> Self {
+        Self { out, during: None, result: Ok(()) }
+    }
+
+    /// The output, or the first error writing to it.
+    pub fn finish(self) -> io::Result<W> {
+        self.result.map(|_| self.out)
+    }
+}
+
+impl<W: Write> Observer for Trace<W> {
+    fn observe(&mut self, _cycle: i32, cpu: &CPU) {
+        let instruction = cpu.current_instruction().map_or("-".to_string(), |i| i.to_string());
+        self.during = Some((instruction, cpu.ticks_to_completion(), cpu.register('X')));
+    }
+
+    fn after(&mut self, cycle: i32, cpu: &CPU) {
+        let Some((instruction, ticks, before)) = self.during.take() else {
+            return;
+        };
+        if self.result.is_ok() {
+            self.result = writeln!(self.out, "{:>5}  {:<10} {} to go  X {} -> {}", cycle, instruction, ticks, before, cpu.register('X'));
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Comparison {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+const COMPARISONS: [(&str, Comparison); 6] = [
+    ("==", Comparison::Equal),
+    ("!=", Comparison::NotEqual),
+    ("<=", Comparison::LessOrEqual),
+    (">=", Comparison::GreaterOrEqual),
+    ("<", Comparison::Less),
+    (">", Comparison::Greater),
+];
+
+/// Stops the debugger once a register compares to a value, written like
+/// `X==21` or `X>=30`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Breakpoint {
+    register: char,
+    comparison: Comparison,
+    value: i32,
+}
+
+impl Breakpoint {
+    pub fn holds(&self, cpu: &CPU) -> bool {
+        let register = cpu.register(self.register);
+        match self.comparison {
+            Comparison::Equal => register == self.value,
+            Comparison::NotEqual => register != self.value,
+            Comparison::Less => register < self.value,
+            Comparison::LessOrEqual => register <= self.value,
+            Comparison::Greater => register > self.value,
+            Comparison::GreaterOrEqual => register >= self.value,
+        }
+    }
+}
+
+impl FromStr for Breakpoint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.replace(' ', "");
+        let error = || format!("expected a breakpoint like X==21 or X>=30, found {:?}", s);
+
+        let mut chars = s.chars();
+        let register = chars.next().filter(char::is_ascii_uppercase).ok_or_else(error)?;
+        let rest = chars.as_str();
+        let (op, comparison) = COMPARISONS.iter().find(|(op, _)| rest.starts_with(op)).ok_or_else(error)?;
+        let value = rest[op.len()..].parse().map_err(|_| error())?;
+
+        Ok(Self { register, comparison: *comparison, value })
+    }
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let op = COMPARISONS.iter().find(|(_, c)| *c == self.comparison).unwrap().0;
+        write!(f, "{}{}{}", self.register, op, self.value)
+    }
+}
+
+static HELP: &str = "commands:
+  s, step [n]          run n cycles, 1 by default
+  c, continue          run until a breakpoint triggers or the program ends
+  u, until <cycle>     run until <cycle> is the next one, breakpoints still stop it
+  b, break <X==21>     stop once a register starts to compare like that, also != < <= > >=
+  d, delete <n>        remove breakpoint n
+  l, breakpoints       list the breakpoints
+  r, registers         show the registers that aren't 0, and X
+  q, queue [n]         show the current instruction and the next n, 10 by default
+  h, help              show this
+  exit                 leave the debugger";
+
+/// Runs a CPU a command at a time. A breakpoint triggers when its condition
+/// starts to hold, so `X>=20` stops once rather than on every cycle after.
+pub struct Debugger {
+    cpu: CPU,
+    breakpoints: Vec<Breakpoint>,
+}
+
+impl Debugger {
+    pub fn new(cpu: CPU) -> Self {
+        Self { cpu, breakpoints: vec![] }
+    }
+
+    pub fn cpu(&self) -> &CPU {
+        &self.cpu
+    }
+
+    /// Reads commands from `input` until it ends or says `exit`.
+    pub fn run<R: BufRead, W: Write>(&mut self, input: R, mut out: W) -> io::Result<()> {
+        writeln!(out, "{}", self.state())?;
+
+        for line in input.lines() {
+            let line = line?;
+            if line.trim() == "exit" {
+                break;
+            }
+            match self.command(&line) {
+                Ok(reply) => writeln!(out, "{}", reply)?,
+                Err(err) => writeln!(out, "{}", err)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Runs one command, returning what to show.
+    pub fn command(&mut self, line: &str) -> Result<String, String> {
+        let mut words = line.split_whitespace();
+        let command = words.next().unwrap_or("step");
+        let argument = words.collect::<Vec<&str>>().join(" ");
+        let number = |default: Option<i32>| match argument.as_str() {
+            "" => default.ok_or_else(|| format!("{} needs a number", command)),
+            n => n.parse::<i32>().map_err(|_| format!("expected a number, found {:?}", n)),
+        };
+
+        match command {
+            "s" | "step" => {
+                let cycles = number(Some(1))?;
+                let stop = self.cpu.cycle() + cycles.max(0);
+                Ok(self.run_until(|cpu| cpu.cycle() >= stop))
+            },
+            "c" | "continue" => Ok(self.run_until(|cpu| cpu.is_done())),
+            "u" | "until" => {
+                let cycle = number(None)?;
+                Ok(self.run_until(|cpu| cpu.cycle() + 1 >= cycle || cpu.is_done()))
+            },
+            "b" | "break" => {
+                let breakpoint = argument.parse::<Breakpoint>()?;
+                self.breakpoints.push(breakpoint);
+                Ok(format!("breakpoint {}: {}", self.breakpoints.len(), breakpoint))
+            },
+            "d" | "delete" => {
+                let n = number(None)?;
+                match n >= 1 && n as usize <= self.breakpoints.len() {
+                    true => Ok(format!("deleted breakpoint {}: {}", n, self.breakpoints.remove(n as usize - 1))),
+                    false => Err(format!("there is no breakpoint {}", n)),
+                }
+            },
+            "l" | "breakpoints" => Ok(match self.breakpoints.is_empty() {
+                true => "no breakpoints".to_string(),
+                false => self.breakpoints.iter().enumerate().map(|(i, b)| format!("{}: {}", i + 1, b)).collect::<Vec<_>>().join("\n"),
+            }),
+            "r" | "registers" => Ok(self.registers()),
+            "q" | "queue" => Ok(self.queue(number(Some(10))?.max(0) as usize)),
+            "h" | "help" => Ok(HELP.to_string()),
+            _ => Err(format!("unknown command {:?}, try help", command)),
+        }
+    }
+
+    /// Ticks until `stop` holds, a breakpoint triggers or the program ends,
+    /// not at all if `stop` already holds.
+    fn run_until(&mut self, mut stop: impl FnMut(&CPU) -> bool) -> String {
+        let mut triggered = vec![];
+
+        while !self.cpu.is_done() && !stop(&self.cpu) {
+            let held = self.breakpoints.iter().map(|b| b.holds(&self.cpu)).collect::<Vec<bool>>();
+            self.cpu.tick();
+
+            triggered = self
+                .breakpoints
+                .iter()
+                .zip(held)
+                .enumerate()
+                .filter(|(_, (b, held))| !held && b.holds(&self.cpu))
+                .map(|(i, (b, _))| format!("breakpoint {}: {}", i + 1, b))
+                .collect();
+            if !triggered.is_empty() {
+                break;
+            }
+        }
+
+        triggered.push(self.state());
+        triggered.join("\n")
+    }
+
+    fn state(&self) -> String {
+        match self.cpu.current_instruction() {
+            Some(instruction) => format!(
+                "cycle {} next: {} ({} to go), X={}",
+                self.cpu.cycle() + 1,
+                instruction,
+                self.cpu.ticks_to_completion(),
+                self.cpu.register('X')
+            ),
+            None => format!("program done after cycle {}, X={}", self.cpu.cycle(), self.cpu.register('X')),
+        }
+    }
+
+    fn registers(&self) -> String {
+        ('A'..='Z')
+            .filter(|r| *r == 'X' || self.cpu.register(*r) != 0)
+            .map(|r| format!("{}={}", r, self.cpu.register(r)))
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    fn queue(&self, n: usize) -> String {
+        let Some(current) = self.cpu.current_instruction() else {
+            return "nothing left to run".to_string();
+        };
+        let pending = self.cpu.pending().count();
+
+        let mut lines = vec![format!("> {} ({} to go)", current, self.cpu.ticks_to_completion())];
+        lines.extend(self.cpu.pending().take(n).map(|i| format!("  {}", i)));
+        if pending > n {
+            lines.push(format!("  ... {} more", pending - n));
+        }
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse_program;
+
+    #[test]
+    fn trace_01() {
+        let mut cpu = CPU::new("assets/input_test_01.txt");
+        let mut trace = Trace::new(vec![]);
+        cpu.run_with(&mut trace);
+
+        let trace = String::from_utf8(trace.finish().unwrap()).unwrap();
+        assert_eq!(trace.lines().collect::<Vec<_>>(), vec![
+            "    1  noop       1 to go  X 1 -> 1",
+            "    2  addx 3     2 to go  X 1 -> 1",
+            "    3  addx 3     1 to go  X 1 -> 4",
+            "    4  addx -5    2 to go  X 4 -> 4",
+            "    5  addx -5    1 to go  X 4 -> -1",
+        ]);
+    }
+
+    #[test]
+    fn debugger_01() {
+        let program = parse_program(&aoc_input::read_file("assets/input_test_02.txt").unwrap()).unwrap();
+        let mut debugger = Debugger::new(CPU::from_program(&program));
+
+        assert_eq!(debugger.command("until 20").unwrap(), "cycle 20 next: addx -1 (2 to go), X=21");
+        assert_eq!(debugger.command("step 2").unwrap(), "cycle 22 next: addx 5 (2 to go), X=20");
+        assert_eq!(debugger.command("step 0").unwrap(), "cycle 22 next: addx 5 (2 to go), X=20");
+        assert_eq!(debugger.command("until 10").unwrap(), "cycle 22 next: addx 5 (2 to go), X=20");
+        assert_eq!(debugger.cpu().cycle(), 21);
+        assert_eq!(debugger.command("queue 1").unwrap(), "> addx 5 (2 to go)\n  addx -1\n  ... 133 more");
+
+        assert_eq!(debugger.command("break X<=12").unwrap(), "breakpoint 1: X<=12");
+        assert_eq!(debugger.command("c").unwrap(), "breakpoint 1: X<=12\ncycle 40 next: addx 1 (2 to go), X=1");
+        assert_eq!(debugger.command("b X > 30").unwrap(), "breakpoint 2: X>30");
+        assert!(debugger.command("b Y~1").is_err());
+        assert_eq!(debugger.command("d 1").unwrap(), "deleted breakpoint 1: X<=12");
+        assert_eq!(debugger.command("l").unwrap(), "1: X>30");
+        assert_eq!(debugger.command("continue").unwrap(), "breakpoint 1: X>30\ncycle 56 next: addx -15 (2 to go), X=34");
+
+        assert_eq!(debugger.command("d 1").unwrap(), "deleted breakpoint 1: X>30");
+        assert_eq!(debugger.command("continue").unwrap(), "program done after cycle 240, X=17");
+        assert_eq!(debugger.command("r").unwrap(), "X=17");
+    }
+
+    #[test]
+    fn debugger_02() {
+        let mut debugger = Debugger::new(CPU::new("assets/input_test_01.txt"));
+        let mut out = vec![];
+        debugger.run("s\nfoo\nexit\ns\n".as_bytes(), &mut out).unwrap();
+
+        assert_eq!(String::from_utf8(out).unwrap(), [
+            "cycle 1 next: noop (1 to go), X=1",
+            "cycle 2 next: addx 3 (2 to go), X=1",
+            "unknown command \"foo\", try help",
+            "",
+        ].join("\n"));
+        assert_eq!(debugger.cpu().cycle(), 1);
+    }
+}
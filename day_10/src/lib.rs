@@ -1,163 +1,237 @@
-use std::{collections::{VecDeque, HashMap}, time::Instant};
+mod assembler;
+mod cpu;
+mod debug;
+mod ocr;
+
+use std::io;
+use std::num::NonZeroUsize;
+
+use aoc_grid::Grid;
+use aoc_input::ParseError;
+use aoc_solution::Solution;
+
+pub use assembler::assemble;
+pub use cpu::{Definition, Instruction, InstructionSet, Observer, Operand, OperandKind, Registers, ADDX, CPU, NOOP};
+pub use debug::{Breakpoint, Debugger, Trace};
+pub use ocr::{encode, FONT};
+
+/// The sprite is `sprite_width` pixels wide, centred on `X`, an even width
+/// reaching one pixel further right than left. The beam moves on to the
+/// next row after `row_width` pixels, columns past that stay dark.
+pub struct Screen {
+    cpu: CPU,
+    pixels: Grid<bool>,
+    sprite_width: NonZeroUsize,
+    row_width: usize,
+}
 
-mod input_reader;
+/// The cycles the signal strength is sampled during: `first` and every
+/// `every` cycles after, only `first` if `every` is 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Schedule {
+    pub first: i32,
+    pub every: u32,
+}
 
-#[derive(Debug, Copy, Clone)]
-pub enum Instruction {
-    Noop,
-    Addx(i32)
+impl Default for Schedule {
+    /// The 20th cycle and every 40th after.
+    fn default() -> Self {
+        Self { first: 20, every: 40 }
+    }
 }
-pub struct CPU {
-    instructions: VecDeque<Instruction>,
-    cycle: i32,
-    register: i32,
-    current_instruction: Option<Instruction>,
-    ticks_to_completion: i32,
-    sum_of_signal_strenghts: i32,
+
+impl Schedule {
+    pub fn contains(&self, cycle: i32) -> bool {
+        match self.every {
+            0 => cycle == self.first,
+            every => cycle >= self.first && (cycle as i64 - self.first as i64) % every as i64 == 0,
+        }
+    }
 }
 
-pub struct Screen {
-    cpu: CPU,
-    pixels: HashMap<(i32,i32), bool>,
-    dimensions: (i32, i32)
+/// Adds up the signal strength during the cycles of `schedule`.
+#[derive(Debug, Default)]
+pub struct SignalStrength {
+    pub schedule: Schedule,
+    pub sum: i32,
 }
 
+impl Observer for SignalStrength {
+    fn observe(&mut self, cycle: i32, cpu: &CPU) {
+        if self.schedule.contains(cycle) {
+            self.sum += cycle * cpu.register('X');
+        }
+    }
+}
+
+fn sum_of_signal_strengths(mut cpu: CPU, schedule: Schedule) -> i32 {
+    let mut strength = SignalStrength { schedule, sum: 0 };
+    cpu.run_with(&mut strength);
+    strength.sum
+}
 
+pub fn part_01(path: Option<&str>) -> i32 {
+    part_01_with(path, Schedule::default())
+}
+
+pub fn part_01_with(path: Option<&str>, schedule: Schedule) -> i32 {
+    let sum = sum_of_signal_strengths(CPU::new(path.unwrap_or("assets/input.txt")), schedule);
+    println!("Part 1 - Sum of signal strengths: {}", sum);
 
-pub fn part_01(){
-    let started = Instant::now();
-    let mut cpu = CPU::new("assets/input.txt");
-    cpu.run();
-    println!("Part 1 - Sum of signal strengths: {}", cpu.sum_of_signal_strenghts);
-    println!("Execution time for part 1: {:?}", Instant::now() - started)
+    sum
 }
 
-pub fn part_02(){
-    let started = Instant::now();
-    let mut screen = Screen::new("assets/input.txt", (40,6));
+pub fn part_02(path: Option<&str>) -> String {
+    let mut screen = Screen::new(path.unwrap_or("assets/input.txt"), (40,6));
 
     screen.load_pixels();
-    println!("Part 2");
+    let letters = screen.decode();
+    println!("Part 2 - The screen reads {}", letters);
     screen.print();
-    println!("Execution time for part 2: {:?}", Instant::now() - started);   
 
+    letters
+}
+
+/// Prints the program running, a line per cycle.
+pub fn trace(path: Option<&str>) {
+    let mut cpu = CPU::new(path.unwrap_or("assets/input.txt"));
+    let mut trace = Trace::new(io::stdout());
+
+    cpu.run_with(&mut trace);
+    trace.finish().unwrap_or_else(aoc_input::exit);
+}
+
+/// Steps through the program with commands read from stdin.
+pub fn debug(path: Option<&str>) {
+    let mut debugger = Debugger::new(CPU::new(path.unwrap_or("assets/input.txt")));
+
+    println!("type help for the commands");
+    debugger.run(io::stdin().lock(), io::stdout().lock()).unwrap_or_else(aoc_input::exit);
+}
+
+/// A bitmap drawn with `#` for lit pixels and `.` for dark ones.
+pub fn parse_bitmap(input: &str) -> Result<Grid<bool>, ParseError> {
+    Grid::parse(input, "'#' or '.'", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+}
+
+/// Prints a program drawing `pixels`, and what it draws to check it.
+pub fn print_program(pixels: &Grid<bool>) {
+    let program = assemble(pixels).unwrap_or_else(|| aoc_input::exit("no program can draw that"));
+    for instruction in &program {
+        println!("{}", instruction);
+    }
+
+    let mut screen = Screen::from_cpu(CPU::from_program(&program), (pixels.width() as i32, pixels.height() as i32));
+    screen.load_pixels();
+    eprintln!("{} instructions drawing\n{}", program.len(), screen.render());
 }
 
 impl Screen {
     pub fn new(input:&str, dimensions: (i32, i32)) -> Self {
-        Self { 
-            cpu: CPU::new(input), 
-            pixels: HashMap::new(), 
-            dimensions: dimensions
-        }
+        Self::from_cpu(CPU::new(input), dimensions)
     }
 
-    pub fn load_pixels(&mut self){
-        for y in 0..self.dimensions.1 {
-            for x in 0..self.dimensions.0 {                
-                let current_register = self.cpu.register;
-                let pixel_visible = (current_register - x).abs() <= 1;
-                self.pixels.insert((x,y), pixel_visible);
-                self.cpu.tick();
-            }
+    pub fn from_cpu(cpu: CPU, dimensions: (i32, i32)) -> Self {
+        Self { 
+            cpu, 
+            pixels: Grid::new(dimensions.0 as usize, dimensions.1 as usize, false),
+            sprite_width: NonZeroUsize::new(3).unwrap(),
+            row_width: dimensions.0 as usize,
         }
     }
 
-    pub fn print(&self){
-        for y in 0..self.dimensions.1 {
-            let mut line = "".to_string();
-            for x in 0..self.dimensions.0 {
-                line.push(if *self.pixels.get(&(x,y)).unwrap() { '▩' } else { ' '} )
-            }
-            println!("{}", line)
-        }
+    pub fn with_sprite_width(mut self, sprite_width: NonZeroUsize) -> Self {
+        self.sprite_width = sprite_width;
+        self
     }
 
+    pub fn with_row_width(mut self, row_width: usize) -> Self {
+        self.row_width = row_width.max(1);
+        self
+    }
 
-}
-
-impl CPU {
-    pub fn new(input_file: &str) -> Self {
-        let lines = input_reader::read_lines(input_file);
-        let mut instructions:VecDeque<Instruction> = VecDeque::new();
-        for line in lines {
-            instructions.push_front(parse_instruction(&line))
-        };
-        
-        let mut init_state = Self {
-            current_instruction: None,
-            instructions,
-            cycle: 0,
-            register: 1,
-            ticks_to_completion: 0,
-            sum_of_signal_strenghts: 0
-        };
-
-        init_state.load_next_instruction();
-        init_state
-    } 
+    /// Runs the program, drawing a pixel per cycle. Pixels after the end of
+    /// the program stay dark, cycles after the last pixel draw nothing.
+    pub fn load_pixels(&mut self){
+        let pixels = &mut self.pixels;
+        let row_width = self.row_width as isize;
+        let sprite_width = self.sprite_width.get() as i64;
+        let (left, right) = ((sprite_width - 1) / 2, sprite_width / 2);
+
+        self.cpu.run_with(&mut |cycle: i32, cpu: &CPU| {
+            let index = cycle as isize - 1;
+            let (x, y) = (index % row_width, index / row_width);
+            if pixels.contains((x, y)) {
+                let offset = x as i64 - cpu.register('X') as i64;
+                pixels[(x, y)] = -left <= offset && offset <= right;
+            }
+        });
+    }
 
-    pub fn signal_strengh(&self) -> i32{
-        self.cycle * self.register
+    pub fn render(&self) -> String {
+        self.pixels.rows()
+            .map(|row| row.iter().map(|lit| if *lit { '▩' } else { ' ' }).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
     }
 
-    pub fn tick(&mut self)-> (){
+    /// The letters on the screen, `?` for the ones it can't make out.
+    pub fn decode(&self) -> String {
+        ocr::decode(&self.pixels)
+    }
 
-        self.cycle += 1;
-        self.ticks_to_completion -= 1;
+    pub fn print(&self){
+        println!("{}", self.render())
+    }
 
-        if self.cycle == 20 || (self.cycle - 20) % 40 == 0 {
-            // println!("Adding new signal_strenght. Cycle: {}, Register: {}", self.completed_cycles, self.register);
-            self.sum_of_signal_strenghts += self.cycle * self.register;
-        }
 
-        match &self.current_instruction {
-            Some(Instruction::Addx(add_to_register)) => {
-                if self.ticks_to_completion == 0 {
-                    self.register += add_to_register;
-                }
-            },
-            Some(Instruction::Noop) => (),
-            None => println!("No instructions left")
-        }
+}
 
-        // println!("Cycle: {}, Ticks to completion: {}, Current Instruction: {:?}", self.completed_cycles, self.ticks_to_completion, self.current_instruction);
-        if self.ticks_to_completion == 0 {
-            self.load_next_instruction()
-        }
+#[allow(non_camel_case_types)]
+#[derive(Default)]
+pub struct AOC_2022_10 {
+    program: Vec<Instruction>
+}
+impl AOC_2022_10 {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
 
-        if self.ticks_to_completion < 0 {
-            panic!("Ticks to completion smaller 0. Should not have happened");
-        }
+impl Solution for AOC_2022_10 {
+    type Part1 = i32;
+    type Part2 = String;
 
-        
+    fn name(&self) -> (usize, usize) {
+        (2022,10)
     }
 
-    pub fn load_next_instruction(&mut self) -> (){
-        self.current_instruction = self.instructions.pop_back();
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+        self.program = parse_program(input)?;
+        Ok(())
+    }
 
-        match self.current_instruction {
-            Some(Instruction::Addx(_)) => self.ticks_to_completion = 2,
-            Some(Instruction::Noop) => self.ticks_to_completion = 1,
-            None => ()
-        }
+    fn part1(&self) -> i32 {
+        sum_of_signal_strengths(CPU::from_program(&self.program), Schedule::default())
     }
 
-    pub fn run(&mut self){
-        while let Some(_) = &self.current_instruction {
-            // println!("Cycle: {}, Register: {}", self.completed_cycles, self.register);
-            self.tick()
-        }
+    fn part2(&self) -> String {
+        let mut screen = Screen::from_cpu(CPU::from_program(&self.program), (40,6));
+        screen.load_pixels();
+        screen.decode()
     }
 }
 
-pub fn parse_instruction(line:&str) -> Instruction {
-    let parts = line.split(" ").collect::<Vec<&str>>();
-    match parts[0] {
-        "addx" => Instruction::Addx(parts[1].parse::<i32>().unwrap()),
-        "noop" => Instruction::Noop,
-        _ => panic!("Unkown instructions")
-    }
+pub fn parse_program(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    InstructionSet::default().parse_program(input)
+}
+
+pub fn parse_instruction(line:&str) -> Result<Instruction, ParseError> {
+    InstructionSet::default().parse_instruction(line)
 }
 
 #[cfg(test)]
@@ -169,15 +243,98 @@ mod test {
         let mut state = CPU::new("assets/input_test_01.txt");
         state.run();
 
-        assert_eq!(state.cycle, 5);
+        assert_eq!(state.cycle(), 5);
+        assert_eq!(state.register('X'), -1);
     }
 
     #[test]
     fn run_02(){
-        let mut state = CPU::new("assets/input_test_02.txt");
-        state.run();
+        let sum = sum_of_signal_strengths(CPU::new("assets/input_test_02.txt"), Schedule::default());
+
+        assert_eq!(sum, 13140);
+    }
+
+    #[test]
+    fn parse_program_01(){
+        let err = parse_program("noop\naddx 3\naddx\n").unwrap_err();
 
-        assert_eq!(state.sum_of_signal_strenghts, 13140);
+        assert_eq!((err.line, err.column, err.expected.as_str()), (3, 5, "a value to add"));
+        assert!(parse_program("noop \naddx -5\t\n  \n").is_ok());
+    }
+
+    #[test]
+    fn solution_01(){
+        let mut solution = AOC_2022_10::new();
+        solution.parse(&aoc_input::read_file("assets/input_test_02.txt").unwrap()).unwrap();
+
+        assert_eq!(solution.part1(), 13140);
+        assert_eq!(solution.part2(), "????????");
+
+        let mut screen = Screen::new("assets/input_test_02.txt", (40,6));
+        screen.load_pixels();
+        assert_eq!(screen.render().lines().next().unwrap(), "▩▩  ▩▩  ▩▩  ▩▩  ▩▩  ▩▩  ▩▩  ▩▩  ▩▩  ▩▩  ");
+    }
+
+    #[test]
+    fn schedule_01(){
+        let schedule = Schedule { first: 60, every: 80 };
+        assert!(schedule.contains(60) && schedule.contains(220) && !schedule.contains(20) && !schedule.contains(100));
+        assert!(Schedule { first: 20, every: 0 }.contains(20) && !Schedule { first: 20, every: 0 }.contains(60));
+
+        // 20th, 60th, ... signal strengths in the puzzle: 420, 1140, 1800, 2940, 2880, 3960
+        assert_eq!(part_01_with(Some("assets/input_test_02.txt"), schedule), 1140 + 2940 + 3960);
+        assert_eq!(part_01_with(Some("assets/input_test_02.txt"), Schedule { first: 220, every: 0 }), 3960);
+    }
+
+    #[test]
+    fn screen_settings_01(){
+        let lit = |screen: &Screen| screen.pixels.values().filter(|lit| **lit).count();
+        let load = |mut screen: Screen| { screen.load_pixels(); screen };
+        let width = |n: usize| NonZeroUsize::new(n).unwrap();
+
+        let default = load(Screen::new("assets/input_test_02.txt", (40,6)));
+        let three = load(Screen::new("assets/input_test_02.txt", (40,6)).with_sprite_width(width(3)));
+        assert_eq!(default.render(), three.render());
+
+        let one = load(Screen::new("assets/input_test_02.txt", (40,6)).with_sprite_width(width(1)));
+        let five = load(Screen::new("assets/input_test_02.txt", (40,6)).with_sprite_width(width(5)));
+        assert!(one.pixels.points().all(|p| !one.pixels[p] || default.pixels[p]));
+        assert!(default.pixels.points().all(|p| !default.pixels[p] || five.pixels[p]));
+        assert!(lit(&one) < lit(&default) && lit(&default) < lit(&five));
+        // only the pixel X points at
+        assert_eq!(one.render().lines().take(2).collect::<Vec<&str>>(), vec![
+            " ▩   ▩  ▩   ▩    ▩                      ",
+            "  ▩     ▩    ▩     ▩      ▩     ▩    ▩  ",
+        ]);
+
+        // the beam wrapping after 20 pixels draws the left halves of the
+        // rows of 40 pixels on every other row
+        let narrow = load(Screen::new("assets/input_test_02.txt", (20,12)).with_row_width(20));
+        let narrow_rows = narrow.render().lines().map(|l| l.to_string()).collect::<Vec<String>>();
+        let left_halves = default.render().lines().map(|l| l.chars().take(20).collect::<String>()).collect::<Vec<String>>();
+        assert_eq!(narrow_rows.iter().step_by(2).cloned().collect::<Vec<String>>(), left_halves);
+
+        let dark = load(Screen::new("assets/input_test_02.txt", (40,6)).with_row_width(30));
+        assert!(dark.pixels.column(35).all(|lit| !lit));
+    }
+
+    #[test]
+    fn part_02_01(){
+        assert_eq!(part_02(None), "EPJBRKAH");
+    }
+
+    #[test]
+    fn parse_bitmap_01(){
+        let pixels = parse_bitmap("##.#.\n.##..\n").unwrap();
+        assert_eq!(pixels, Grid::from_vec(5, 2, vec![true, true, false, true, false, false, true, true, false, false]));
+
+        let err = parse_bitmap("##.#.\n.#▩..\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+
+        let program = assemble(&pixels).unwrap();
+        let mut screen = Screen::from_cpu(CPU::from_program(&program), (5, 2));
+        screen.load_pixels();
+        assert_eq!(screen.pixels, pixels);
     }
 
     #[test]
@@ -1,6 +1,41 @@
-use day_10::{part_01, part_02};
+use std::{env, process};
+
+use day_10::{debug, encode, parse_bitmap, part_01, part_02, print_program, trace};
+
+static USAGE: &str = "usage: day_10 [--trace | --debug] [<input>]
+       day_10 --assemble <LETTERS>
+       day_10 --assemble-bitmap <bitmap.txt>
+
+--assemble prints a program drawing up to 8 capital letters, the bitmap of
+--assemble-bitmap is drawn with # and . and may have any size. Only images
+starting with two lit pixels can be drawn, so text has to start with one of
+B, E, F, P, R or Z.";
+
+fn usage_error() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2);
+}
 
 fn main() {
-    part_01();
-    part_02();
+    let args = env::args().skip(1).collect::<Vec<String>>();
+
+    match args.iter().map(|a| a.as_str()).collect::<Vec<&str>>()[..] {
+        [] => {
+            part_01(None);
+            part_02(None);
+        },
+        ["--trace"] => trace(None),
+        ["--trace", path] if !path.starts_with("--") => trace(Some(path)),
+        ["--debug"] => debug(None),
+        ["--debug", path] if !path.starts_with("--") => debug(Some(path)),
+        ["--assemble", letters] => {
+            let pixels = encode(letters, 40).unwrap_or_else(|| aoc_input::exit(format!("can't draw {:?} in 8 letters of the AoC font", letters)));
+            print_program(&pixels);
+        },
+        ["--assemble-bitmap", path] => {
+            let input = aoc_input::read_file(path).unwrap_or_else(aoc_input::exit);
+            print_program(&parse_bitmap(&input).unwrap_or_else(aoc_input::exit));
+        },
+        _ => usage_error(),
+    }
 }
@@ -0,0 +1,114 @@
+use aoc_grid::Grid;
+
+pub const GLYPH_WIDTH: usize = 4;
+pub const GLYPH_HEIGHT: usize = 6;
+
+/// The capital letters AoC draws, 4 pixels wide and 6 high. Not every
+/// letter of the alphabet shows up in puzzle answers, so not every letter
+/// is here.
+pub const FONT: [(char, [&str; GLYPH_HEIGHT]); 17] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// The letter whose glyph has its top left corner at `(left, 0)`, `?` for
+/// anything that isn't one.
+fn letter_at(pixels: &Grid<bool>, left: usize) -> char {
+    FONT.iter()
+        .find(|(_, glyph)| {
+            glyph.iter().enumerate().all(|(y, row)| {
+                row.chars().enumerate().all(|(x, c)| pixels.get(((left + x) as isize, y as isize)) == Some(&(c == '#')))
+            })
+        })
+        .map_or('?', |(letter, _)| *letter)
+}
+
+/// Reads the letters off the top of `pixels`, one glyph every 5 pixels
+/// with a blank column in between.
+pub fn decode(pixels: &Grid<bool>) -> String {
+    (0..(pixels.width() + 1) / (GLYPH_WIDTH + 1))
+        .map(|i| letter_at(pixels, i * (GLYPH_WIDTH + 1)))
+        .collect()
+}
+
+/// `text` drawn in the AoC font from the left of a screen `width` pixels
+/// wide, `None` if a letter isn't in the font or the text doesn't fit.
+pub fn encode(text: &str, width: usize) -> Option<Grid<bool>> {
+    let mut pixels = Grid::new(width, GLYPH_HEIGHT, false);
+
+    for (i, letter) in text.chars().enumerate() {
+        let (_, glyph) = FONT.iter().find(|(l, _)| *l == letter)?;
+        let left = i * (GLYPH_WIDTH + 1);
+        if left + GLYPH_WIDTH > width {
+            return None;
+        }
+
+        for (y, row) in glyph.iter().enumerate() {
+            for (x, c) in row.chars().enumerate() {
+                pixels[((left + x) as isize, y as isize)] = c == '#';
+            }
+        }
+    }
+
+    Some(pixels)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn pixels(rows: &[&str]) -> Grid<bool> {
+        let cells = rows.iter().flat_map(|row| row.chars().map(|c| c == '#')).collect();
+        Grid::from_vec(rows[0].len(), rows.len(), cells)
+    }
+
+    #[test]
+    fn decode_01() {
+        let hi = pixels(&[
+            "#..#..###.",
+            "#..#...#..",
+            "####...#..",
+            "#..#...#..",
+            "#..#...#..",
+            "#..#..###.",
+        ]);
+        assert_eq!(decode(&hi), "HI");
+        assert_eq!(decode(&Grid::new(9, 6, false)), "??");
+
+        let all = FONT.iter().map(|(letter, _)| *letter).collect::<String>();
+        let rows = (0..GLYPH_HEIGHT)
+            .map(|y| FONT.iter().map(|(_, glyph)| glyph[y]).collect::<Vec<&str>>().join("."))
+            .collect::<Vec<String>>();
+        assert_eq!(decode(&pixels(&rows.iter().map(|r| r.as_str()).collect::<Vec<&str>>())), all);
+        assert_eq!(decode(&encode(&all, rows[0].len()).unwrap()), all);
+    }
+
+    #[test]
+    fn encode_01() {
+        assert_eq!(encode("HI", 10).unwrap(), pixels(&[
+            "#..#..###.",
+            "#..#...#..",
+            "####...#..",
+            "#..#...#..",
+            "#..#...#..",
+            "#..#..###.",
+        ]));
+        assert_eq!(encode("HI", 8), None);
+        assert_eq!(encode("hi", 40), None);
+    }
+}
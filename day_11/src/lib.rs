@@ -1,5 +1,6 @@
-use std::{collections::VecDeque, time::Instant };
-mod input_reader;
+use std::{cmp::Reverse, collections::VecDeque };
+use aoc_input::{ParseError, Tokens};
+use aoc_solution::Solution;
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Monkey {
@@ -32,132 +33,230 @@ pub struct MonkeyTest {
     throw_to_if_false: i64
 }
 
-pub fn part_01(){
-    let started = Instant::now();
-    let binding = input_reader::read_lines("assets/input.txt");
+pub fn part_01(path: Option<&str>) -> i64 {
+    let binding = aoc_input::read_lines(path.unwrap_or("assets/input.txt")).unwrap_or_else(aoc_input::exit);
     let input: Vec<&str> = binding 
     .iter()
     .map(|s|{s as &str})
     .collect::<Vec<&str>>();
 
-    let mut monkeys = parse_monkeys(&input);
-    
-    for _round_nr in 0..20 {
-        execute_round(&mut monkeys, 0)
-    }
-
-    monkeys.sort_by(|a,b| b.items_inspected.cmp(&a.items_inspected) );
+    let monkeys = parse_monkeys(&input).unwrap_or_else(aoc_input::exit);
+    let monkey_business = monkey_business(&monkeys, 20, false);
+    println!("Part 1 - Level of monkey business: {:#?}", monkey_business);
 
-    println!("Part 1 - Level of monkey business: {:#?}", monkeys[0].items_inspected * monkeys[1].items_inspected);
-    println!("Execution time for part 1: {:?}", Instant::now()-started);
+    monkey_business
 }
 
-pub fn part_02(){
-    let started = Instant::now();
-    let binding = input_reader::read_lines("assets/input.txt");
+pub fn part_02(path: Option<&str>) -> i64 {
+    let binding = aoc_input::read_lines(path.unwrap_or("assets/input.txt")).unwrap_or_else(aoc_input::exit);
     let input: Vec<&str> = binding 
     .iter()
     .map(|s|{s as &str})
     .collect::<Vec<&str>>();
 
-    let mut monkeys = parse_monkeys(&input);
-    let super_modulo = monkeys.iter().fold(1, |sum, val|{ sum * val.test.divide_condition });
+    let monkeys = parse_monkeys(&input).unwrap_or_else(aoc_input::exit);
+    let monkey_business = monkey_business(&monkeys, 10000, true);
+    println!("Part 2 - Level of monkey business: {:#?}", monkey_business);
+
+    monkey_business
+}
+
+pub fn monkey_business(monkeys: &[Monkey], rounds: usize, worried: bool) -> i64 {
+    let mut monkeys = monkeys.to_vec();
+    let super_modulo = if worried {
+        monkeys.iter().fold(1, |sum, val|{ sum * val.test.divide_condition })
+    } else {
+        0
+    };
     
-    for _round_nr in 0..10000 {
+    for _round_nr in 0..rounds {
         execute_round(&mut monkeys, super_modulo)
     }
 
-    monkeys.sort_by(|a,b| b.items_inspected.cmp(&a.items_inspected) );
+    monkeys.sort_by_key(|m| Reverse(m.items_inspected));
 
-    println!("Part 2 - Level of monkey business: {:#?}", monkeys[0].items_inspected * monkeys[1].items_inspected );
-    println!("Execution time for part 2: {:?}", Instant::now()-started);
+    monkeys[0].items_inspected * monkeys[1].items_inspected
 }
 
+#[allow(non_camel_case_types)]
+#[derive(Default)]
+pub struct AOC_2022_11 {
+    monkeys: Vec<Monkey>
+}
+impl AOC_2022_11 {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Solution for AOC_2022_11 {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn name(&self) -> (usize, usize) {
+        (2022,11)
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+        self.monkeys = parse_monkeys(&input.lines().collect::<Vec<&str>>())?;
+        Ok(())
+    }
+
+    fn part1(&self) -> i64 {
+        monkey_business(&self.monkeys, 20, false)
+    }
+
+    fn part2(&self) -> i64 {
+        monkey_business(&self.monkeys, 10000, true)
+    }
+}
 
 impl Monkey {
 
-    pub fn new(lines: &Vec<&str>) -> Self {
+    /// Errors are numbered by their line within `lines`, starting at 1.
+    pub fn new(lines: &[&str]) -> Result<Self, ParseError> {
         if lines.len() != 6 {
-            panic!("Cannot parse monkey. Line count is not 6")
+            return Err(ParseError::new(1, "6 lines per monkey", format!("{} lines", lines.len())).at_line(lines.len()));
         };
 
-        Self {
-            items: parse_items(&lines[1]),
-            operation: parse_operation(&lines[2]),
-            test: parse_test(&lines[3..].to_vec()),
+        let mut header = Tokens::new(lines[0]);
+        header.keyword("Monkey").map_err(|e| e.at_line(1))?;
+        header.number::<usize>("the monkey's number").map_err(|e| e.at_line(1))?;
+
+        Ok(Self {
+            items: parse_items(lines[1]).map_err(|e| e.at_line(2))?,
+            operation: parse_operation(lines[2]).map_err(|e| e.at_line(3))?,
+            test: parse_test(&lines[3..]).map_err(|e| e.shift_lines(3))?,
             items_inspected: 0
-        }
+        })
     }
 }
 
-pub fn parse_items(line: &str) -> VecDeque<i64> {
-    
-    VecDeque::from(
-        line[17..].to_string()
-    .split(", ")
-    .map(|f| { f.trim().parse::<i64>().unwrap() })
-    .collect::<Vec<i64>>()
-    ) 
-}
+pub fn parse_items(line: &str) -> Result<VecDeque<i64>, ParseError> {
+    let mut tokens = Tokens::new(line);
+    tokens.keyword("Starting")?;
+    tokens.keyword("items")?;
 
-pub fn parse_operation(line:&str) -> Operation {
-    let fn_str = line[23..]
-    .split(" ")
-    .map(|f| f.trim())
-    .collect::<Vec<&str>>();
+    let mut items = VecDeque::new();
+    while !tokens.is_empty() {
+        items.push_back(tokens.number::<i64>("a worry level")?);
+    }
 
+    Ok(items)
+}
 
-    let operand = fn_str[1].parse::<i64>();
-    
-    match (fn_str[0], fn_str[1], operand) {
-        ("*", "old", _) => Operation { operator: Operator::MultiplySelf, operand: 0 },
-        ("+", "old", _) => Operation { operator: Operator::PlusSelf, operand: 0 },
-        ("*", _, Ok(operand)) => Operation { operator: Operator::Multiply, operand },
-        ("/", _, Ok(operand)) => Operation { operator: Operator::Divide, operand },
-        ("-", _, Ok(operand)) => Operation { operator: Operator::Minus, operand },
-        ("+", _, Ok(operand)) => Operation { operator: Operator::Plus, operand },        
-        unknown_operator => panic!("{:?} is not a known operator", unknown_operator)
+pub fn parse_operation(line:&str) -> Result<Operation, ParseError> {
+    let mut tokens = Tokens::new(line);
+    for keyword in ["Operation", "new", "=", "old"] {
+        tokens.keyword(keyword)?;
     }
+
+    let operator = tokens.one_of(&["+", "-", "*", "/"])?;
+    let mut lookahead = tokens.clone();
+    let operation = match (operator, lookahead.word("a number or 'old'")?) {
+        ("*", "old") => {
+            tokens = lookahead;
+            Operation { operator: Operator::MultiplySelf, operand: 0 }
+        },
+        ("+", "old") => {
+            tokens = lookahead;
+            Operation { operator: Operator::PlusSelf, operand: 0 }
+        },
+        (operator, _) => {
+            let operand = tokens.number::<i64>("a number")?;
+            let operator = match operator {
+                "*" => Operator::Multiply,
+                "/" => Operator::Divide,
+                "-" => Operator::Minus,
+                _ => Operator::Plus,
+            };
+            Operation { operator, operand }
+        }
+    };
+    tokens.end()?;
+
+    Ok(operation)
 }
 
-pub fn parse_test(lines: &Vec<&str>) -> MonkeyTest{
+/// Errors are numbered by their line within `lines`, starting at 1.
+pub fn parse_test(lines: &[&str]) -> Result<MonkeyTest, ParseError> {
     if lines.len() != 3 {
-        panic!("tests must consist of three lines");
+        return Err(ParseError::new(1, "3 lines per test", format!("{} lines", lines.len())).at_line(lines.len()));
     }
 
-    let divide_condition = lines[0].split(" ").last().unwrap().parse::<i64>().unwrap();
-    let throw_to_if_true = lines[1].split(" ").last().unwrap().parse::<i64>().unwrap();
-    let throw_to_if_false = lines[2].split(" ").last().unwrap().parse::<i64>().unwrap();
+    let mut condition = Tokens::new(lines[0]);
+    for keyword in ["Test", "divisible", "by"] {
+        condition.keyword(keyword).map_err(|e| e.at_line(1))?;
+    }
+    let divide_condition = condition
+        .parse_with("a divisor above 0", |token| token.parse::<i64>().ok().filter(|d| *d > 0))
+        .map_err(|e| e.at_line(1))?;
+
+    let throw_to = |line: &str, outcome: &str| -> Result<i64, ParseError> {
+        let mut tokens = Tokens::new(line);
+        for keyword in ["If", outcome, "throw", "to", "monkey"] {
+            tokens.keyword(keyword)?;
+        }
+        tokens.number::<i64>("a monkey to throw to")
+    };
+    let throw_to_if_true = throw_to(lines[1], "true").map_err(|e| e.at_line(2))?;
+    let throw_to_if_false = throw_to(lines[2], "false").map_err(|e| e.at_line(3))?;
 
-    MonkeyTest { divide_condition , throw_to_if_true, throw_to_if_false }
+    Ok(MonkeyTest { divide_condition , throw_to_if_true, throw_to_if_false })
 }
 
-pub fn parse_monkeys(lines: &Vec<&str>) -> Vec<Monkey> {
+/// Monkeys separated by blank lines, at least two of them since the two
+/// busiest are what the puzzle asks for, throwing only to monkeys in the list.
+pub fn parse_monkeys(lines: &[&str]) -> Result<Vec<Monkey>, ParseError> {
     let mut monkeys:Vec<Monkey> = Vec::new();
-    
-    for (i, line) in lines.iter().enumerate() {
-        let first_word = line.split(" ").collect::<Vec<&str>>()[0];
-        
-        match first_word{
-            "Monkey" => {
-                let monkey_block = lines[i..i+6].to_vec();
-                monkeys.push(Monkey::new(&monkey_block));
-            },
-            _ => ()
+    let mut starts:Vec<usize> = Vec::new();
+
+    let mut i = 0;
+    while i < lines.len() {
+        if lines[i].trim().is_empty() {
+            i += 1;
+            continue;
         }
+
+        let monkey_block = &lines[i..lines.len().min(i+6)];
+        monkeys.push(Monkey::new(monkey_block).map_err(|e| e.shift_lines(i))?);
+        starts.push(i);
+        i += 6;
     };
 
-    monkeys
+    if monkeys.len() < 2 {
+        return Err(ParseError::new(1, "at least 2 monkeys", format!("{} monkeys", monkeys.len())).at_line(lines.len().max(1)));
+    }
+
+    let expected = format!("a monkey from 0 to {}", monkeys.len() - 1);
+    for (start, monkey) in starts.iter().zip(monkeys.iter()) {
+        let throws = [(4, "true", monkey.test.throw_to_if_true), (5, "false", monkey.test.throw_to_if_false)];
+        for (offset, outcome, target) in throws {
+            if (0..monkeys.len() as i64).contains(&target) {
+                continue;
+            }
+
+            // read the line again to point at the target
+            let mut tokens = Tokens::new(lines[start + offset]);
+            for keyword in ["If", outcome, "throw", "to", "monkey"] {
+                tokens.keyword(keyword)?;
+            }
+            let err = tokens.parse_with(&expected, |_| None::<usize>).unwrap_err();
+            return Err(err.at_line(start + offset + 1));
+        }
+    }
+
+    Ok(monkeys)
 
 }
 
-pub fn execute_round(monkeys: &mut Vec<Monkey>, super_modulo: i64){
+pub fn execute_round(monkeys: &mut [Monkey], super_modulo: i64){
 
     for monkey_index in 0..monkeys.len() {
         let operand = monkeys[monkey_index].operation.operand;
-        let monkey = &monkeys.clone()[monkey_index];
+        let monkey = &monkeys.to_vec()[monkey_index];
 
-        while let Some(mut item) = monkeys[monkey_index as usize].items.pop_front() {            
+        while let Some(mut item) = monkeys[monkey_index].items.pop_front() {            
             item = match monkeys[monkey_index].operation.operator {
                 Operator::Divide => item / operand,
                 Operator::Minus => item - operand,
@@ -168,14 +267,14 @@ pub fn execute_round(monkeys: &mut Vec<Monkey>, super_modulo: i64){
             };
             
             if super_modulo == 0 {
-                item = item/3;
+                item /= 3;
             } else {
-                item = item % super_modulo;
+                item %= super_modulo;
             }
 
-            monkeys[monkey_index as usize].items_inspected += 1;
+            monkeys[monkey_index].items_inspected += 1;
             
-            if item % monkeys[monkey_index as usize].test.divide_condition == 0 {
+            if item % monkeys[monkey_index].test.divide_condition == 0 {
                 monkeys[monkey.test.throw_to_if_true as usize].items.push_back(item);
             } else {
                 monkeys[monkey.test.throw_to_if_false as usize].items.push_back(item);
@@ -191,38 +290,44 @@ pub fn execute_round(monkeys: &mut Vec<Monkey>, super_modulo: i64){
 
 
 #[cfg(test)]
-
 mod test {
     use super::*;
 
     #[test]
     fn parse_items_01(){
-        let items = parse_items("  Starting items: 79, 98");
+        let items = parse_items("  Starting items: 79, 98").unwrap();
 
         assert_eq!(items, vec![79,98]);
     }
 
     #[test]
     fn parse_operation_01(){
-        let op = parse_operation("  Operation: new = old + 5");
+        let op = parse_operation("  Operation: new = old + 5").unwrap();
 
         assert_eq!(op, Operation{operand: 5, operator: Operator::Plus });
     }
 
     #[test]
     fn parse_operation_02(){
-        let op = parse_operation("  Operation: new = old + old");
+        let op = parse_operation("  Operation: new = old + old").unwrap();
 
         assert_eq!(op, Operation{operand: 0, operator: Operator::PlusSelf });
     }
 
+    #[test]
+    fn parse_operation_03(){
+        let err = parse_operation("  Operation: new = old % 5").unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (24, "'+' or '-' or '*' or '/'"));
+
+        let err = parse_operation("  Operation: new = old * ol").unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (26, "ol"));
+    }
+
     #[test]
     fn parse_test_01(){
-        let test = parse_test(&vec![
-            "  Test: divisible by 17",
+        let test = parse_test(&["  Test: divisible by 17",
             "    If true: throw to monkey 0",
-            "    If false: throw to monkey 1"
-        ]);
+            "    If false: throw to monkey 1"]).unwrap();
 
         assert_eq!(test, MonkeyTest{
             divide_condition: 17,
@@ -233,14 +338,12 @@ mod test {
 
     #[test]
     fn parse_monkey_01(){
-        let monkey = Monkey::new(&vec![
-            "Monkey 0:",
+        let monkey = Monkey::new(&["Monkey 0:",
             "  Starting items: 79, 98",
             "  Operation: new = old * 19",
             "  Test: divisible by 23",
             "    If true: throw to monkey 2",
-            "    If false: throw to monkey 3"
-        ]);
+            "    If false: throw to monkey 3"]).unwrap();
 
         assert_eq!(monkey, Monkey {
             items: VecDeque::from( vec![79,98] ) ,
@@ -280,7 +383,7 @@ mod test {
             "  Test: divisible by 17",
             "    If true: throw to monkey 0",
             "    If false: throw to monkey 1",
-        ]);
+        ]).unwrap();
 
         assert_eq!(monkeys, vec![
             Monkey{
@@ -311,6 +414,55 @@ mod test {
         ])
     }
 
+    #[test]
+    fn parse_monkeys_02(){
+        let err = parse_monkeys(&[
+            "Monkey 0:",
+            "  Starting items: 79, 98",
+            "  Operation: new = old * 19",
+            "  Test: divisible by 23",
+            "    If true: throw to monkey 2",
+            "    If false: throw to monkey 3",
+            "",
+            "Monkey 1:",
+            "  Starting items: 54, 65,",
+            "  Operation: new = old + 6",
+            "  Test: divisible by 19",
+            "    If true: throw to monkey 2",
+            "    If false: throw to monkey",
+        ]).unwrap_err();
+
+        assert_eq!((err.line, err.column), (13, 30));
+
+        let monkey = |n: usize, divisor: &str, to: &str| vec![
+            format!("Monkey {}:", n),
+            "  Starting items: 79".to_string(),
+            "  Operation: new = old * 19".to_string(),
+            format!("  Test: divisible by {}", divisor),
+            "    If true: throw to monkey 1".to_string(),
+            format!("    If false: throw to monkey {}", to),
+        ];
+        let parse = |blocks: Vec<Vec<String>>| {
+            let lines = blocks.join(&String::new());
+            parse_monkeys(&lines.iter().map(|l| l.as_str()).collect::<Vec<&str>>())
+        };
+
+        assert!(parse(vec![monkey(0, "23", "1"), monkey(1, "19", "0")]).is_ok());
+
+        let err = parse(vec![monkey(0, "23", "1"), monkey(1, "19", "5")]).unwrap_err();
+        assert_eq!((err.line, err.column, err.expected.as_str(), err.found.as_str()), (13, 31, "a monkey from 0 to 1", "5"));
+
+        let err = parse(vec![monkey(0, "0", "1"), monkey(1, "19", "0")]).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (4, 22, "0"));
+
+        let err = parse(vec![monkey(0, "23", "1")]).unwrap_err();
+        assert_eq!((err.line, err.expected.as_str()), (6, "at least 2 monkeys"));
+
+        assert_eq!(parse_monkeys(&[]).unwrap_err().expected, "at least 2 monkeys");
+        let err = parse(vec![monkey(0, "23", "1"), vec!["garbage".to_string()], monkey(1, "19", "0")]).unwrap_err();
+        assert_eq!((err.line, err.column, err.expected.as_str(), err.found.as_str()), (8, 1, "'Monkey'", "garbage"));
+    }
+
     #[test]
     fn execute_round_01(){
         let mut monkeys = parse_monkeys(&vec![
@@ -341,7 +493,7 @@ mod test {
             "  Test: divisible by 17",
             "    If true: throw to monkey 0",
             "    If false: throw to monkey 1",
-        ]);
+        ]).unwrap();
 
        execute_round(&mut monkeys, 0);
 
@@ -0,0 +1,181 @@
+use aoc_grid::{Grid, Point};
+use aoc_input::ParseError;
+use aoc_pathfinder::Pathfinder;
+use aoc_solution::Solution;
+
+mod route;
+
+pub use route::render_route;
+
+/// The heightmap, `a` to `z` stored as 0 to 25. `S` has the height of `a` and
+/// `E` the height of `z`.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct HeightMap {
+    heights: Grid<u8>,
+    start: Point,
+    end: Point,
+}
+
+impl HeightMap {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let map = Grid::parse(input, "a height from 'a' to 'z', 'S' or 'E'", |c| {
+            matches!(c, 'a'..='z' | 'S' | 'E').then_some(c)
+        })?;
+        let start = map.position(|c| *c == 'S').ok_or_else(|| ParseError::new(1, "a starting point 'S'", ""))?;
+        let end = map.position(|c| *c == 'E').ok_or_else(|| ParseError::new(1, "a best signal point 'E'", ""))?;
+        let heights = map.map(|c| match c {
+            'S' => 0,
+            'E' => 25,
+            c => *c as u8 - b'a',
+        });
+
+        Ok(Self { heights, start, end })
+    }
+
+    /// The height as a letter, or `S` and `E` for the start and end.
+    fn symbol(&self, point: Point) -> char {
+        match point {
+            p if p == self.start => 'S',
+            p if p == self.end => 'E',
+            p => (b'a' + self.heights[p]) as char,
+        }
+    }
+
+    /// A shortest route from `from` to any point for which `is_goal` holds,
+    /// both ends included, only stepping from height `a` to `b` where
+    /// `can_step(a, b)`.
+    fn bfs<G, C>(&self, from: Point, is_goal: G, can_step: C) -> Option<Vec<Point>>
+    where
+        G: Fn(&Point) -> bool,
+        C: Fn(u8, u8) -> bool,
+    {
+        let can_step = &can_step;
+        let neighbours = |&point: &Point| {
+            let height = self.heights[point];
+            self.heights
+                .neighbours_4(point)
+                .filter(move |next| can_step(height, self.heights[*next]))
+                .map(|next| (next, 1))
+        };
+
+        Pathfinder::new(neighbours).bfs([from], is_goal).map(|path| path.states)
+    }
+}
+
+pub fn part_01(path: Option<&str>) -> usize {
+    let input = aoc_input::read_file(path.unwrap_or("assets/input.txt")).unwrap_or_else(aoc_input::exit);
+    let map = HeightMap::new(&input).unwrap_or_else(aoc_input::exit);
+    let steps = steps_from_start(&map).expect("no path from S to E");
+
+    println!("Part 1 - shortest path from S-E takes {} steps", steps);
+
+    steps
+}
+
+pub fn part_02(path: Option<&str>) -> usize {
+    let input = aoc_input::read_file(path.unwrap_or("assets/input.txt")).unwrap_or_else(aoc_input::exit);
+    let map = HeightMap::new(&input).unwrap_or_else(aoc_input::exit);
+    let steps = steps_from_lowest_point(&map).expect("no path from any a to E");
+
+    println!("Part 2 - shortest path from a-E takes {} steps", steps);
+
+    steps
+}
+
+pub fn steps_from_start(map: &HeightMap) -> Option<usize> {
+    route_from_start(map).map(|route| route.len() - 1)
+}
+
+pub fn steps_from_lowest_point(map: &HeightMap) -> Option<usize> {
+    route_from_lowest_point(map).map(|route| route.len() - 1)
+}
+
+/// Climbing at most one step up, any step down.
+pub fn route_from_start(map: &HeightMap) -> Option<Vec<Point>> {
+    map.bfs(map.start, |point| *point == map.end, |from, to| to <= from + 1)
+}
+
+/// Walks backwards from `E` with the climb rule inverted, so the first `a`
+/// reached is the one closest to `E`. The route is turned around to start
+/// at that `a`.
+pub fn route_from_lowest_point(map: &HeightMap) -> Option<Vec<Point>> {
+    let mut route = map.bfs(map.end, |point| map.heights[*point] == 0, |from, to| from <= to + 1)?;
+    route.reverse();
+
+    Some(route)
+}
+
+/// Prints the routes of both parts on the map at `path`.
+pub fn print_routes(path: Option<&str>, coloured: bool) {
+    let input = aoc_input::read_file(path.unwrap_or("assets/input.txt")).unwrap_or_else(aoc_input::exit);
+    let map = HeightMap::new(&input).unwrap_or_else(aoc_input::exit);
+
+    for (part, route) in [("Part 1 - from S", route_from_start(&map)), ("Part 2 - from the closest a", route_from_lowest_point(&map))] {
+        match route {
+            Some(route) => println!("{} in {} steps\n{}\n", part, route.len() - 1, render_route(&map, &route, coloured)),
+            None => println!("{}: no route to E\n", part),
+        }
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Default)]
+pub struct AOC_2022_12 {
+    map: HeightMap,
+}
+impl AOC_2022_12 {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Solution for AOC_2022_12 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn name(&self) -> (usize, usize) {
+        (2022, 12)
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+        self.map = HeightMap::new(input)?;
+
+        Ok(())
+    }
+
+    fn part1(&self) -> usize {
+        steps_from_start(&self.map).expect("no path from S to E")
+    }
+
+    fn part2(&self) -> usize {
+        steps_from_lowest_point(&self.map).expect("no path from any a to E")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn height_map_01() {
+        let map = HeightMap::new("Sab\nfca\naEa\n").unwrap();
+
+        assert_eq!(map.heights.rows().collect::<Vec<&[u8]>>(), vec![&[0, 0, 1], &[5, 2, 0], &[0, 25, 0]]);
+        assert_eq!((map.start, map.end), ((0, 0), (1, 2)));
+
+        let err = HeightMap::new("Sab\nf1a\naEa\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 2, "1"));
+        assert_eq!(HeightMap::new("Sab\nfc\naEa\n").unwrap_err().line, 2);
+        assert_eq!(HeightMap::new("Sab\n").unwrap_err().expected, "a best signal point 'E'");
+    }
+
+    #[test]
+    fn part_01_01() {
+        assert_eq!(part_01(Some("assets/input_test.txt")), 31);
+    }
+
+    #[test]
+    fn part_02_01() {
+        assert_eq!(part_02(Some("assets/input_test.txt")), 29);
+    }
+}
@@ -0,0 +1,24 @@
+use std::{env, process};
+
+use day_12::{part_01, part_02, print_routes};
+
+static USAGE: &str = "usage: day_12 [--route [--plain] [<input>]]";
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+
+    match args.iter().map(|a| a.as_str()).collect::<Vec<&str>>()[..] {
+        [] => {
+            part_01(None);
+            part_02(None);
+        },
+        ["--route"] => print_routes(None, true),
+        ["--route", "--plain"] => print_routes(None, false),
+        ["--route", path] if !path.starts_with("--") => print_routes(Some(path), true),
+        ["--route", "--plain", path] => print_routes(Some(path), false),
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    }
+}
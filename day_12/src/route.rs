@@ -0,0 +1,69 @@
+use colored::Colorize;
+use aoc_grid::Point;
+
+use crate::HeightMap;
+
+fn arrow(from: Point, to: Point) -> char {
+    match (to.0 - from.0, to.1 - from.1) {
+        (1, 0) => '>',
+        (-1, 0) => '<',
+        (0, 1) => 'v',
+        _ => '^',
+    }
+}
+
+/// The map with `route` drawn on it like the puzzle's diagrams: each step
+/// an arrow towards the next one, the last point as its letter and `.` off
+/// the route. Coloured, the route is highlighted and the heights off the
+/// route stay visible, dimmed.
+pub fn render_route(map: &HeightMap, route: &[Point], coloured: bool) -> String {
+    let mut marks = map.heights.map(|_| None);
+    for step in route.windows(2) {
+        marks[step[0]] = Some(arrow(step[0], step[1]));
+    }
+    if let Some(last) = route.last() {
+        marks[*last] = Some(map.symbol(*last));
+    }
+
+    marks
+        .rows()
+        .enumerate()
+        .map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .map(|(x, mark)| match (mark, coloured) {
+                    (Some(mark), true) => mark.to_string().bold().green().to_string(),
+                    (Some(mark), false) => mark.to_string(),
+                    (None, true) => map.symbol((x as isize, y as isize)).to_string().dimmed().to_string(),
+                    (None, false) => ".".to_string(),
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{route_from_lowest_point, route_from_start};
+
+    #[test]
+    fn render_route_01() {
+        let map = HeightMap::new(&aoc_input::read_file("assets/input_test.txt").unwrap()).unwrap();
+        let rendered = render_route(&map, &route_from_start(&map).unwrap(), false);
+
+        // as long as the puzzle's own route, this one heads right first
+        assert_eq!(rendered, ">>vv<<<<\n..vvv<<^\n..vv>E^^\n..v>>>^^\n..>>>>>^");
+    }
+
+    #[test]
+    fn render_route_02() {
+        let map = HeightMap::new(&aoc_input::read_file("assets/input_test.txt").unwrap()).unwrap();
+        let rendered = render_route(&map, &route_from_lowest_point(&map).unwrap(), false);
+
+        assert_eq!(rendered.matches(['>', '<', '^', 'v']).count(), 29);
+        assert_eq!(rendered.matches('E').count(), 1);
+        assert_eq!(render_route(&map, &[], false).lines().next(), Some("........"));
+    }
+}
@@ -1,6 +1,7 @@
-use std::{collections::{HashMap, VecDeque}, borrow::Borrow, time::Instant };
+use std::collections::{HashMap, VecDeque};
+use aoc_input::ParseError;
+use aoc_solution::Solution;
 
-mod input_reader;
 
 #[ derive(Debug, PartialEq, Clone, Default) ]
 struct Location {
@@ -21,21 +22,33 @@ struct MapGrid {
     solutions: HashMap<(usize,usize), usize>
 }
 
-pub fn part_01(){
-    let started = Instant::now();
-    let input = input_reader::read_lines("assets/input.txt");
-    let mut mg = MapGrid::new(input);   
+pub fn part_01(path: Option<&str>) -> usize {
+    let input = aoc_input::read_lines(path.unwrap_or("assets/input.txt")).unwrap_or_else(aoc_input::exit);
+    let min_steps_start_to_end = steps_from_start(&input);
 
-    mg.run_until_goal_reached();
+    println!("Part 1 - shortest path from S-E takes {} steps", min_steps_start_to_end);
+
+    min_steps_start_to_end
+}
+
+pub fn part_02(path: Option<&str>) -> usize {
+    let input = aoc_input::read_lines(path.unwrap_or("assets/input.txt")).unwrap_or_else(aoc_input::exit);
+    let shortest_path_overall = steps_from_lowest_point(&input);
+
+    println!("Part 2 - shortest path from a-E takes {} steps", shortest_path_overall);
+
+    shortest_path_overall
+}
+
+pub fn steps_from_start(input: &[String]) -> usize {
+    let mut mg = MapGrid::new(input.to_vec());   
 
-    println!("Part 1 - shortest path from S-E takes {} steps", mg.min_steps_start_to_end);
-    println!("Execution time for part 1: {:?}", Instant::now() -  started);
+    mg.run_until_goal_reached();
+    mg.min_steps_start_to_end
 }
 
-pub fn part_02(){
-    let started = Instant::now();
-    let input = input_reader::read_lines("assets/input.txt");
-    let mg = MapGrid::new(input.clone());
+pub fn steps_from_lowest_point(input: &[String]) -> usize {
+    let mg = MapGrid::new(input.to_vec());
 
     let mut shortest_path_overall = 1000;
     for (point, location) in mg.map {
@@ -43,16 +56,15 @@ pub fn part_02(){
             let mut starting_point_in_reach = true;
             println!("Starting run for point: {:?}", point);
 
-            let mut alt_mg = MapGrid::new(input.clone());
+            let mut alt_mg = MapGrid::new(input.to_vec());
             alt_mg.starting_point = point;
             alt_mg.remove_points_out_of_reach(shortest_path_overall);
             
-            if let None = alt_mg.map.get(&point) {
+            if !alt_mg.map.contains_key(&point) {
                 println!("Current starting point out of reach. Won't execute run");
                 starting_point_in_reach = false;
             }
 
-            let started_run = Instant::now();
             
             if starting_point_in_reach {
                 if let Some(shortest_path_current) = alt_mg.run_until_goal_reached_limited(shortest_path_overall) {
@@ -63,13 +75,46 @@ pub fn part_02(){
                 }
             }
             
-            println!("Run completed after {:?}. Shortest path currently: {}", Instant::now()-started_run, shortest_path_overall);
+            println!("Run completed. Shortest path currently: {}", shortest_path_overall);
 
         }
     }
 
-    println!("Part 2 - shortest path from a-E takes {} steps", shortest_path_overall);
-    println!("Execution time for part 1: {:?}", Instant::now() -  started);
+    shortest_path_overall
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Default)]
+pub struct AOC_2022_12 {
+    lines: Vec<String>
+}
+impl AOC_2022_12 {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Solution for AOC_2022_12 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn name(&self) -> (usize, usize) {
+        (2022,12)
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+        self.lines = input.lines().filter(|l| !l.is_empty()).map(|l| l.to_string()).collect();
+
+        Ok(())
+    }
+
+    fn part1(&self) -> usize {
+        steps_from_start(&self.lines)
+    }
+
+    fn part2(&self) -> usize {
+        steps_from_lowest_point(&self.lines)
+    }
 }
 
 impl MapGrid {
@@ -112,7 +157,7 @@ impl MapGrid {
         let mut steps_from_target = 0;
         for point in self.clone().map.get(&self.end_point).unwrap().shortest_path.clone() {
             steps_from_target += 1;
-            let mut location = self.map.get_mut(&point).unwrap();
+            let location = self.map.get_mut(&point).unwrap();
             location.distance_to_target = Some(steps_from_target);
             
             self.solutions.insert(point, steps_from_target);
@@ -128,9 +173,7 @@ impl MapGrid {
 
         let previous_location = map.get(&previous_point).unwrap();
 
-        current_location.shortest_path = Vec::from(
-            previous_location.shortest_path.borrow(),
-        );        
+        current_location.shortest_path = previous_location.shortest_path.clone();        
 
         if current_location.point == self.end_point && self.min_steps_start_to_end == 0{
             self.min_steps_start_to_end = current_location.shortest_path.len();            
@@ -207,7 +250,7 @@ fn get_possible_locations(map: HashMap<(usize,usize), Location>, current_point:(
 
         let incrementors:Vec<(i32,i32)> = vec![(0,1),(1,0),(-1,0),(0,-1)];        
         for inc in incrementors {
-            let pt = (current_point.0 as i32 - inc.0 as i32, current_point.1 as i32-inc.1 as i32);
+            let pt = (current_point.0 as i32 - inc.0, current_point.1 as i32-inc.1);
             if pt.0 >= 0 && pt.1 >= 0 {
 
                 if let Some(pl) = map.get(&(pt.0 as usize, pt.1 as usize)) {
@@ -227,14 +270,13 @@ fn get_possible_locations(map: HashMap<(usize,usize), Location>, current_point:(
 }
 
 #[cfg(test)]
-
 mod test {
     use super::*;
 
 
     #[test]
     fn map_grid_01(){
-        let input:Vec<String> = vec![
+        let input:Vec<String> = [
         "Sab",
         "fca",
         "aEa"
@@ -259,7 +301,7 @@ mod test {
 
     #[test]
     fn map_run_01(){
-        let input = input_reader::read_lines("assets/input_test.txt");
+        let input = aoc_input::read_lines("assets/input_test.txt").unwrap();
         let mut mg = MapGrid::new(input);
 
         mg.run_until_goal_reached();
@@ -1,6 +1,6 @@
-use day_12::{part_01, part_02};
+use day_12_dead_end::{part_01, part_02};
 
 fn main() {
-    part_01();
-    part_02();
+    part_01(None);
+    part_02(None);
 }
@@ -1,63 +1,76 @@
-mod input_reader;
 
-use std::time::Instant;
-use input_reader::read_lines;
 use std::cmp::Ordering;
+use aoc_input::ParseError;
+use aoc_solution::Solution;
 
 
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone)]
 pub enum Item {
     Num(i32),
     ItemList(Vec<Item>)
 }
 
 pub fn part_01(path: Option<&str>) ->i32 {
-    let started = Instant::now();
-    let input = read_lines(path.unwrap_or("assets/input.txt"));
-    let mut input_iter = input.iter();
+    let input = aoc_input::read_lines(path.unwrap_or("assets/input.txt")).unwrap_or_else(aoc_input::exit);
+    let packets = parse_packets(&input).unwrap_or_else(aoc_input::exit);
 
-    let mut indizes_sum = 0;
-    let mut index = 1;
-    
-    while let Some(fl) = input_iter.next() {
-        
-        let left = parse_item_list(&mut fl.chars());
-        let right = parse_item_list(&mut input_iter.next().unwrap().chars());
-        
-        input_iter.next();
+    let indizes_sum = index_sum_in_order(&packets);
+
+    println!("part 01 - index sum: {}", indizes_sum);
+    println!("pairs processed: {}", packets.len() / 2);
 
-        if item_list_in_order(&left, &right).unwrap() {
-            indizes_sum += index;
+    indizes_sum
+}
+
+pub fn part_02(path: Option<&str>) -> i32 {
+    let input = aoc_input::read_lines(path.unwrap_or("assets/input.txt")).unwrap_or_else(aoc_input::exit);
+    let packets = parse_packets(&input).unwrap_or_else(aoc_input::exit);
+
+    let i = decoder_key(&packets);
+
+    println!("Part02 - Multiplied indices: {}", i);
+
+    i
+}
+
+/// One packet per non-blank line, read in pairs, so every packet needs
+/// another one to pair with.
+pub fn parse_packets<S: AsRef<str>>(lines: &[S]) -> Result<Vec<Vec<Item>>, ParseError> {
+    let mut packets = Vec::new();
+    let mut last_line = 0;
+
+    for (i, line) in lines.iter().map(|line| line.as_ref()).enumerate() {
+        if line.trim().is_empty() {
+            continue;
         }
+        packets.push(parse_item_list(line).map_err(|e| e.at_line(i + 1))?);
+        last_line = i + 1;
+    }
 
-        index += 1;
+    if !packets.len().is_multiple_of(2) {
+        return Err(ParseError::new(1, "a packet to pair with the last one", "").at_line(last_line + 1));
     }
 
-    println!("part 01 - index sum: {}", indizes_sum);
-    println!("pairs processed: {}", index-1);
-    println!("Execution time for part 1: {:?}", Instant::now() - started);
+    Ok(packets)
+}
+
+pub fn index_sum_in_order(packets: &[Vec<Item>]) -> i32 {
+    let mut indizes_sum = 0;
+    
+    for (index, pair) in packets.chunks(2).enumerate() {
+        if item_list_in_order(&pair[0], &pair[1]).unwrap() {
+            indizes_sum += index as i32 + 1;
+        }
+    }
 
     indizes_sum
 }
 
-pub fn part_02(path: Option<&str>) -> i32 {
-    let started = Instant::now();
-    let input = read_lines(path.unwrap_or("assets/input.txt"));
-    let mut input_iter = input.iter();
-
-    let mut item_lists:Vec<Vec<Item>> = vec![];
-
-    while let Some(line) = input_iter.next() {
-        if line.len() == 0  { continue; }
-        item_lists.push(
-            parse_item_list(&mut line.chars())
-        );        
-    } 
-    item_lists.extend(vec![
-        parse_item_list(&mut "[[2]]".chars()),
-        parse_item_list(&mut "[[6]]".chars())
-    ]);
+pub fn decoder_key(packets: &[Vec<Item>]) -> i32 {
+    let mut item_lists = packets.to_vec();
+
+    item_lists.extend(vec![divider(2), divider(6)]);
 
     item_lists.sort_by(|left,right|{
         match item_list_in_order(left, right) {
@@ -67,62 +80,121 @@ pub fn part_02(path: Option<&str>) -> i32 {
         }
     });
 
-    let i = item_lists.iter().enumerate().fold(1, |acc, (ind, curr)|{
+    item_lists.iter().enumerate().fold(1, |acc, (ind, curr)|{
         
-        if *curr == parse_item_list(&mut "[[2]]".chars()) ||
-            *curr == parse_item_list(&mut "[[6]]".chars())
-        {
+        if *curr == divider(2) || *curr == divider(6) {
             acc * (ind + 1 ) as i32
         } else {
             acc
         }
-    });
+    })
+}
 
-    println!("Part02 - Multiplied indices: {}", i);
-    println!("Execution time for part 2: {:?}", Instant::now() - started);
+/// The packet `[[n]]`.
+fn divider(n: i32) -> Vec<Item> {
+    vec![Item::ItemList(vec![Item::ItemList(vec![Item::Num(n)])])]
+}
 
-    i
+#[allow(non_camel_case_types)]
+#[derive(Default)]
+pub struct AOC_2022_13 {
+    packets: Vec<Vec<Item>>
+}
+impl AOC_2022_13 {
+    pub fn new() -> Self {
+        Self::default()
+    }
 }
 
+impl Solution for AOC_2022_13 {
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn name(&self) -> (usize, usize) {
+        (2022,13)
+    }
 
-pub fn parse_item_list (input:&mut dyn Iterator<Item = char>) -> Vec<Item> {        
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+        self.packets = parse_packets(&input.lines().collect::<Vec<&str>>())?;
+
+        Ok(())
+    }
+
+    fn part1(&self) -> i32 {
+        index_sum_in_order(&self.packets)
+    }
+
+    fn part2(&self) -> i32 {
+        decoder_key(&self.packets)
+    }
+}
+
+
+/// A whole packet like `[1,[2,3]]`, wrapped in a one item list so it
+/// compares like any nested list.
+pub fn parse_item_list(line: &str) -> Result<Vec<Item>, ParseError> {
+    let chars: Vec<char> = line.trim_end().chars().collect();
+    let mut pos = 0;
+
+    if chars.first() != Some(&'[') {
+        return Err(unexpected(line, &chars, pos, "'['"));
+    }
+    let list = parse_list(line, &chars, &mut pos)?;
+    if pos < chars.len() {
+        return Err(unexpected(line, &chars, pos, "end of line"));
+    }
+
+    Ok(vec![Item::ItemList(list)])
+}
+
+/// The list opening at `chars[*pos]`, leaving `pos` just past its `]`.
+fn parse_list(line: &str, chars: &[char], pos: &mut usize) -> Result<Vec<Item>, ParseError> {
     let mut result:Vec<Item> = Vec::new();
+    *pos += 1;
 
-    let mut digit_str = "".to_string();
+    if chars.get(*pos) == Some(&']') {
+        *pos += 1;
+        return Ok(result);
+    }
 
-    while let Some(c) = input.next() {
-        match c {
-            '[' => {                
-                result.push(Item::ItemList(
-                    parse_item_list(input)
-                ));
-            },
-            ']' => {
-                if let Ok(value) = digit_str.parse::<i32>()  { 
-                    result.push(Item::Num(value))
-                }
-                return result;
-            },
-            ',' => {
-                if let Ok(value) = digit_str.parse::<i32>()  { 
-                    result.push(Item::Num(value))
+    loop {
+        match chars.get(*pos) {
+            Some('[') => result.push(Item::ItemList(parse_list(line, chars, pos)?)),
+            Some(c) if c.is_ascii_digit() => {
+                let start = *pos;
+                while chars.get(*pos).is_some_and(|c| c.is_ascii_digit()) {
+                    *pos += 1;
                 }
-                digit_str = "".to_string();
+                let digits: String = chars[start..*pos].iter().collect();
+                let value = digits.parse::<i32>().map_err(|_| ParseError {
+                    text: line.to_string(),
+                    ..ParseError::new(start + 1, "a number", digits.clone())
+                })?;
+                result.push(Item::Num(value));
             },
-            c if c.is_numeric() => {
-                digit_str.push(c)
+            _ => return Err(unexpected(line, chars, *pos, "a number or '['")),
+        }
+
+        match chars.get(*pos) {
+            Some(',') => *pos += 1,
+            Some(']') => {
+                *pos += 1;
+                return Ok(result);
             },
-            _ => ()
+            _ => return Err(unexpected(line, chars, *pos, "',' or ']'")),
         }
     }
-    result
+}
 
+fn unexpected(line: &str, chars: &[char], pos: usize, expected: &str) -> ParseError {
+    let found = chars.get(pos).map(|c| c.to_string()).unwrap_or_default();
+    ParseError { text: line.to_string(), ..ParseError::new(pos + 1, expected, found) }
 }
 
-pub fn item_list_in_order(left: &Vec<Item>, right: &Vec<Item> ) -> Option<bool> {
+pub fn item_list_in_order(left: &[Item], right: &[Item] ) -> Option<bool> {
     let (mut l, mut r) = (
-        left.clone().iter().peekable(), 
-        right.clone().iter().peekable()
+        left.iter().peekable(), 
+        right.iter().peekable()
     );
 
     let mut result:Option<bool> = None;
@@ -143,7 +215,7 @@ pub fn item_list_in_order(left: &Vec<Item>, right: &Vec<Item> ) -> Option<bool>
                 
             ( Some(Item::Num(a)), Some(Item::ItemList(b)) ) => 
                 if let Some(val) = item_list_in_order( 
-                    &vec![ Item::Num(*a) ], 
+                    &[Item::Num(*a)], 
                     b,                      
                 ) {
                     result = Some(val);
@@ -152,7 +224,7 @@ pub fn item_list_in_order(left: &Vec<Item>, right: &Vec<Item> ) -> Option<bool>
             ( Some(Item::ItemList(a)), Some(Item::Num(b))) => {
                 if let Some(val) = item_list_in_order(
                     a, 
-                    &vec![Item::Num(*b)],
+                    &[Item::Num(*b)],
                 ) {
                     result = Some(val);
                     break;
@@ -191,9 +263,7 @@ mod test {
 
     #[test]
     fn parse_item_list_01(){
-        let mut input = "[1,1,3,1,1]".chars();
-        // let pair = Pair::new(input);
-        let item_list = parse_item_list(&mut input);
+        let item_list = parse_item_list("[1,1,3,1,1]").unwrap();
 
         assert_eq!(item_list, vec![Item::ItemList(vec![
             Item::Num(1),
@@ -207,9 +277,7 @@ mod test {
 
     #[test]
     fn parse_item_list_02(){
-        let mut input = "[[1],[2,3,4]]".chars();
-        // let pair = Pair::new(input);
-        let item_list = parse_item_list(&mut input);
+        let item_list = parse_item_list("[[1],[2,3,4]]").unwrap();
 
         assert_eq!(item_list, vec![Item::ItemList(vec![
             Item::ItemList(
@@ -224,70 +292,96 @@ mod test {
         
     }
 
+    #[test]
+    fn parse_item_list_errors(){
+        assert_eq!(parse_item_list("[[],10]").unwrap(), vec![Item::ItemList(vec![Item::ItemList(vec![]), Item::Num(10)])]);
+
+        for (line, column, expected) in [
+            ("1,2]", 1, "'['"),
+            ("[1,2", 5, "',' or ']'"),
+            ("[1,,2]", 4, "a number or '['"),
+            ("[1;2]", 3, "',' or ']'"),
+            ("[1]]", 4, "end of line"),
+            ("[99999999999]", 2, "a number"),
+        ] {
+            let err = parse_item_list(line).unwrap_err();
+            assert_eq!((err.column, err.expected.as_str()), (column, expected), "{}", line);
+        }
+    }
+
+    #[test]
+    fn parse_packets_01(){
+        let err = parse_packets(&["[1]", "[2]", "", "[1", "[2]"]).unwrap_err();
+        assert_eq!((err.line, err.column), (4, 3));
+
+        let err = parse_packets(&["[1]", "[2]", "", "[3]", ""]).unwrap_err();
+        assert_eq!((err.line, err.expected.as_str()), (5, "a packet to pair with the last one"));
+    }
+
     #[test]
     fn item_list_in_order_01(){
-        let input = input_reader::read_lines("assets/input_test_01.txt");
-        let left = parse_item_list(&mut input[0].chars());
-        let right = parse_item_list(&mut input[1].chars());
+        let input = aoc_input::read_lines("assets/input_test_01.txt").unwrap();
+        let left = parse_item_list(&input[0]).unwrap();
+        let right = parse_item_list(&input[1]).unwrap();
 
         let in_order = item_list_in_order(&left, &right).unwrap();
 
-        assert_eq!(in_order, true);
+        assert!(in_order);
     }
 
     #[test]
     fn item_list_in_order_02(){
-        let input = input_reader::read_lines("assets/input_test_02.txt");
-        let left = parse_item_list(&mut input[0].chars());
-        let right = parse_item_list(&mut input[1].chars());
+        let input = aoc_input::read_lines("assets/input_test_02.txt").unwrap();
+        let left = parse_item_list(&input[0]).unwrap();
+        let right = parse_item_list(&input[1]).unwrap();
 
         let in_order = item_list_in_order(&left, &right).unwrap();
 
-        assert_eq!(in_order, true);
+        assert!(in_order);
     }
 
     #[test]
     fn item_list_in_order_03(){
-        let input = input_reader::read_lines("assets/input_test_03.txt");
-        let left = parse_item_list(&mut input[0].chars());
-        let right = parse_item_list(&mut input[1].chars());
+        let input = aoc_input::read_lines("assets/input_test_03.txt").unwrap();
+        let left = parse_item_list(&input[0]).unwrap();
+        let right = parse_item_list(&input[1]).unwrap();
 
         let in_order = item_list_in_order(&left, &right).unwrap();
 
-        assert_eq!(in_order, false);
+        assert!(!in_order);
     }
 
     #[test]
     fn item_list_in_order_04(){
-        let input = input_reader::read_lines("assets/input_test_04.txt");
-        let left = parse_item_list(&mut input[0].chars());
-        let right = parse_item_list(&mut input[1].chars());
+        let input = aoc_input::read_lines("assets/input_test_04.txt").unwrap();
+        let left = parse_item_list(&input[0]).unwrap();
+        let right = parse_item_list(&input[1]).unwrap();
 
         let in_order = item_list_in_order(&left, &right).unwrap();
 
-        assert_eq!(in_order, true);
+        assert!(in_order);
     }
 
     #[test]
     fn item_list_in_order_05(){
-        let input = input_reader::read_lines("assets/input_test_05.txt");
-        let left = parse_item_list(&mut input[0].chars());
-        let right = parse_item_list(&mut input[1].chars());
+        let input = aoc_input::read_lines("assets/input_test_05.txt").unwrap();
+        let left = parse_item_list(&input[0]).unwrap();
+        let right = parse_item_list(&input[1]).unwrap();
 
         let in_order = item_list_in_order(&left, &right).unwrap();
 
-        assert_eq!(in_order, false);
+        assert!(!in_order);
     }
 
     #[test]
     fn item_list_in_order_06(){
-        let input = input_reader::read_lines("assets/input_test_06.txt");
-        let left = parse_item_list(&mut input[0].chars());
-        let right = parse_item_list(&mut input[1].chars());
+        let input = aoc_input::read_lines("assets/input_test_06.txt").unwrap();
+        let left = parse_item_list(&input[0]).unwrap();
+        let right = parse_item_list(&input[1]).unwrap();
 
         let in_order = item_list_in_order(&left, &right).unwrap();
 
-        assert_eq!(in_order, false);
+        assert!(!in_order);
     }
 
 
@@ -305,4 +399,13 @@ mod test {
         assert_eq!(product, 140)
         
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn solution_01(){
+        let mut solution = AOC_2022_13::new();
+        solution.parse(&aoc_input::read_file("assets/input_test_part01.txt").unwrap()).unwrap();
+
+        assert_eq!(solution.part1(), 13);
+        assert_eq!(solution.part2(), 140);
+    }
+}
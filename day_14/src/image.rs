@@ -0,0 +1,6 @@
+pub use aoc_image::{Image, Rgb};
+
+pub const AIR: Rgb = [24, 24, 32];
+pub const ROCK: Rgb = [120, 120, 120];
+pub const SAND: Rgb = [232, 192, 96];
+pub const SOURCE: Rgb = [224, 48, 48];
@@ -1,7 +1,16 @@
-use std::{collections::HashMap, time::Instant };
+use std::iter;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
 
-use input_reader::read_lines;
-mod input_reader;
+use aoc_grid::{Grid as Cells, Point};
+use aoc_input::{ParseError, Tokens};
+use aoc_solution::Solution;
+
+mod image;
+
+pub use image::Image;
 
 #[derive(Debug, Clone)]
 enum FieldType {
@@ -11,75 +20,171 @@ enum FieldType {
 
 #[derive(Debug, Clone)]
 struct Grid {
-    grid: HashMap<(usize, usize), FieldType>,
-    moving_particles: Vec<(usize, usize)>,
-    sand_source: (usize, usize),    
-    min_x: usize,
-    max_x: usize,
-    max_y: usize,
+    grid: Cells<Option<FieldType>>,
+    /// Where the current grain has fallen through so far, it rests on top.
+    fall_path: Vec<Point>,
+    sand_source: Point,
+    /// Leftmost and rightmost rock or sand, sand spreads past the rocks
+    /// once there is a floor.
+    min_x: isize,
+    max_x: isize,
+    max_y: isize,
     has_floor: bool
 }
 
 pub fn part_01(path: Option<&str>) -> i32 {
-    let started = Instant::now();
-    let input = read_lines(path.unwrap_or("assets/input.txt"));
-    let mut grid = Grid::new(input,false);
-    let mut resting_grains_count = 0;
-    let mut grain_comes_to_rest: Result<(),()> = Ok(());
-
-    while let Ok(()) = grain_comes_to_rest {
-        grain_comes_to_rest = grid.run_single_sand_grain();
-        if let Ok(()) = grain_comes_to_rest { resting_grains_count += 1; }   
-    }
+    let resting_grains_count = resting_grains(&load(path), false);
 
     println!("Part 01 - Grains that can be processed: {}", resting_grains_count);
-    println!("Execution time for part 1: {:?}", Instant::now() - started);    
 
     resting_grains_count
 }
 
 pub fn part_02(path: Option<&str>) -> i32 {
-    let started = Instant::now();
-    let input = read_lines(path.unwrap_or("assets/input.txt"));
-    let mut grid = Grid::new(input, true);
+    let resting_grains_count = resting_grains(&load(path), true);
+
+    println!("Part 02 - Grains that can be processed: {}", resting_grains_count);
+    resting_grains_count
+}
+
+/// The rock paths of the scan at `path`, quitting with the error if it
+/// can't be read.
+fn load(path: Option<&str>) -> Vec<Vec<Point>> {
+    let input = aoc_input::read_file(path.unwrap_or("assets/input.txt")).unwrap_or_else(aoc_input::exit);
+    parse_paths(&input).unwrap_or_else(aoc_input::exit)
+}
+
+/// Every line of the scan, a path of rock like `498,4 -> 498,6 -> 496,6`.
+pub fn parse_paths(input: &str) -> Result<Vec<Vec<Point>>, ParseError> {
+    aoc_input::parse_lines(input, |line| {
+        let mut tokens = Tokens::new(line);
+        let point = |tokens: &mut Tokens| tokens.parse_with("a point like 498,4", |token| {
+            let (x, y) = token.split_once(',')?;
+            Some((x.parse::<isize>().ok()?, y.parse::<isize>().ok().filter(|y| *y >= 0)?))
+        });
+
+        let mut path = vec![point(&mut tokens)?];
+        while !tokens.is_empty() {
+            tokens.keyword("->")?;
+            path.push(point(&mut tokens)?);
+        }
+
+        Ok(path)
+    })
+}
+
+pub fn resting_grains(paths: &[Vec<Point>], has_floor: bool) -> i32 {
+    let mut grid = Grid::new(paths, has_floor);
+
+    iter::from_fn(|| grid.drop_grain()).count() as i32
+}
+
+/// Replays the sand falling on the map at `path` in the terminal, drawing a
+/// frame every `grains_per_frame` grains, `fps` frames a second.
+pub fn animate(path: Option<&str>, has_floor: bool, grains_per_frame: usize, fps: u32) {
+    let mut grid = Grid::new(&load(path), has_floor);
+    let frame_time = Duration::from_secs(1) / fps.max(1);
     let mut resting_grains_count = 0;
-    let mut grain_comes_to_rest: Result<(),()> = Ok(());
 
-    while let Ok(()) = grain_comes_to_rest {
-        grain_comes_to_rest = grid.run_single_sand_grain();
-        resting_grains_count += 1; 
+    loop {
+        let dropped = (0..grains_per_frame.max(1)).map_while(|_| grid.drop_grain()).count();
+        resting_grains_count += dropped;
+
+        // clear the terminal and draw from the top left
+        print!("\x1b[2J\x1b[H");
+        grid.draw();
+        println!("{} grains at rest", resting_grains_count);
+
+        if dropped < grains_per_frame.max(1) {
+            break;
+        }
+        thread::sleep(frame_time);
     }
+}
 
-    println!("Part 02 - Grains that can be processed: {}", resting_grains_count);
-    println!("Execution time for part 2: {:?}", Instant::now() - started);    
-    // grid.draw();
-    resting_grains_count
+/// Writes the cave of the map at `path` to `output` once all sand rests,
+/// as PNG if `output` ends in `.png` and as PPM otherwise. With `every`,
+/// also one numbered image per that many grains, e.g. `cave_000100.png`.
+pub fn export_images(path: Option<&str>, has_floor: bool, output: &Path, scale: usize, every: Option<NonZeroUsize>) {
+    let mut grid = Grid::new(&load(path), has_floor);
+    let save = |image: Image, output: &Path| {
+        image.save(output).unwrap_or_else(|err| aoc_input::exit(format!("{}: {}", output.display(), err)))
+    };
+    let mut resting_grains_count = 0;
+    let mut images = 0;
+
+    while grid.drop_grain().is_some() {
+        resting_grains_count += 1;
+
+        if every.is_some_and(|every| resting_grains_count % every == 0) {
+            save(grid.image(scale), &numbered(output, resting_grains_count));
+            images += 1;
+        }
+    }
+    save(grid.image(scale), output);
+
+    println!("{} grains at rest, wrote {} images", resting_grains_count, images + 1);
+}
+
+/// `output` with `grains` added to its name.
+fn numbered(output: &Path, grains: usize) -> PathBuf {
+    let stem = output.file_stem().and_then(|s| s.to_str()).unwrap_or("cave");
+    let name = match output.extension().and_then(|e| e.to_str()) {
+        Some(ext) => format!("{}_{:06}.{}", stem, grains, ext),
+        None => format!("{}_{:06}", stem, grains),
+    };
+
+    output.with_file_name(name)
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Default)]
+pub struct AOC_2022_14 {
+    paths: Vec<Vec<Point>>
+}
+impl AOC_2022_14 {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Solution for AOC_2022_14 {
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn name(&self) -> (usize, usize) {
+        (2022,14)
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+        self.paths = parse_paths(input)?;
+
+        Ok(())
+    }
+
+    fn part1(&self) -> i32 {
+        resting_grains(&self.paths, false)
+    }
+
+    fn part2(&self) -> i32 {
+        resting_grains(&self.paths, true)
+    }
 }
 
 impl Grid {
-    fn new(input: Vec<String>, has_floor:bool)-> Self {
+    fn new(paths: &[Vec<Point>], has_floor:bool)-> Self {
 
-        let mut grid:HashMap<(usize,usize), FieldType> = HashMap::new();
+        let mut rocks:Vec<Point> = vec![];
         let mut max_x = 0;
-        let mut min_x = usize::MAX;
+        let mut min_x = isize::MAX;
         let mut max_y = 0;
 
-        for line in input {
-            let mut instructions = 
-            line.as_str()
-            .split(" -> ")
-            .map(|i| { i.trim() })
-            .peekable();            
+        for path in paths {
+            for pair in path.windows(2) {
+                let (pt1, pt2) = (pair[0], pair[1]);
 
-            while let (
-                Some(current_instruction),
-                Some(&next_instruction)
-            ) = (instructions.next(), instructions.peek()) {
-                let pt1 = current_instruction.split(",").map(|pos|{pos.parse::<usize>().unwrap()}).collect::<Vec<usize>>();
-                let pt2 = next_instruction.split(",").map(|pos|{pos.parse::<usize>().unwrap()}).collect::<Vec<usize>>();
-
-                let range1 = if pt1[0] > pt2[0] { pt2[0]..=pt1[0] } else { pt1[0]..=pt2[0] };
-                let range2 = if pt1[1] > pt2[1] { pt2[1]..=pt1[1] } else { pt1[1]..=pt2[1] };
+                let range1 = if pt1.0 > pt2.0 { pt2.0..=pt1.0 } else { pt1.0..=pt2.0 };
+                let range2 = if pt1.1 > pt2.1 { pt2.1..=pt1.1 } else { pt1.1..=pt2.1 };
 
 
                 for x in range1.clone() {
@@ -88,7 +193,7 @@ impl Grid {
 
                     for y in range2.clone() {
                         if y > max_y { max_y = y };
-                        grid.insert((x,y), FieldType::Rock);
+                        rocks.push((x, y));
                     }
                 };
 
@@ -96,10 +201,22 @@ impl Grid {
             
         };        
 
+        let sand_source = (500, 0);
+        // sand piles up no wider than it falls deep, one more column on each
+        // side leaves room for the grains that fall past the rocks
+        let spread = max_y + 3;
+        let left = min_x.min(sand_source.0 - spread);
+        let right = max_x.max(sand_source.0 + spread);
+        let mut grid = Cells::new((right - left + 1) as usize, (max_y + 3) as usize, None).with_origin((left, 0));
+
+        for rock in rocks {
+            grid[rock] = Some(FieldType::Rock);
+        }
+
         Self {
             grid,
-            moving_particles: Vec::new(),
-            sand_source: (500, 0),
+            fall_path: Vec::new(),
+            sand_source,
             max_x,
             min_x,
             max_y,
@@ -107,79 +224,107 @@ impl Grid {
         }
     }
 
-    #[allow(dead_code)]
-    fn draw(&self){
-        for y in 0..=(self.max_y+3) {
+    fn is_free(&self, point: Point) -> bool {
+        !matches!(self.grid.get(point), Some(Some(_)))
+    }
+
+    /// The cave like the puzzle draws it, with the path the next grain
+    /// falls along as `~`.
+    fn render(&self) -> String {
+        let mut lines = vec![];
+        for y in 0..=self.floor() {
             let mut line = "".to_string();
             for x in self.min_x..=self.max_x {
-                match self.grid.get(&(x,y)) {
+                let point = (x, y);
+                match self.grid.get(point).and_then(Option::as_ref) {
+                    _ if self.has_floor && y == self.floor() => line.push('#'),
                     Some(FieldType::Rock) => line.push('#'),
-                    Some(FieldType::Sand) => line.push('o'),                    
-                    _ => line.push(' ')
+                    Some(FieldType::Sand) => line.push('o'),
+                    _ if point == self.sand_source => line.push('+'),
+                    _ if self.fall_path.contains(&point) => line.push('~'),
+                    _ => line.push('.')
                 }
             }
-            println!("{}", line);
+            lines.push(line);
         }
+        lines.join("\n")
     }
 
-    #[allow(dead_code, unused)]
-    fn tick(&mut self) -> Result<usize, &str> {
-        let mut indices_not_moving_anymore:Vec<usize> = vec![];        
-        let mut max_x = self.max_x;
-        let mut min_x = self.min_x;
-
-        for (index, particle) in self.moving_particles.iter_mut().enumerate() {
-            let old_position = particle.clone();
-            
-            *particle = match (
-                self.grid.get(&(particle.0, particle.1 + 1)),
-                self.grid.get(&(particle.0-1, particle.1 + 1)),
-                self.grid.get(&(particle.0+1, particle.1 + 1)),
-            ) {
-                (None, _, _) => (particle.0, particle.1 + 1),
-                (Some(_), None, _) => (particle.0 -1, particle.1 + 1),
-                (Some(_), Some(_), None) => (particle.0 +1, particle.1 + 1 ),
-                _ => *particle
-            };
-
-            if old_position == *particle || (self.has_floor && particle.1 == self.max_y+1) {
-                self.grid.insert(*particle, FieldType::Sand);
-                indices_not_moving_anymore.push(index);                
-            }            
-            
-            if !self.has_floor && particle.1 > self.max_y {
-                return Err("particle will not come to rest anymore");
-            }
-
-            if self.has_floor && *particle == (self.sand_source){
-                return Err("particle is already at sand source");
+    /// The whole cave as an image. With a floor that is as wide as the sand
+    /// can ever spread, so every image of a timelapse has the same size.
+    fn image(&self, scale: usize) -> Image {
+        let (left, right) = match self.has_floor {
+            true => (self.grid.origin().0, self.grid.end().0),
+            false => (self.min_x - 1, self.max_x + 1),
+        };
+        let mut image = Image::new((right - left + 1) as usize, self.floor() as usize + 1, scale, image::AIR);
+
+        for y in 0..=self.floor() {
+            for x in left..=right {
+                let colour = match self.grid.get((x, y)).and_then(Option::as_ref) {
+                    _ if self.has_floor && y == self.floor() => image::ROCK,
+                    Some(FieldType::Rock) => image::ROCK,
+                    Some(FieldType::Sand) => image::SAND,
+                    None if (x, y) == self.sand_source => image::SOURCE,
+                    None => image::AIR,
+                };
+                image.set_cell((x - left) as usize, y as usize, colour);
             }
         }
 
-        for ind in indices_not_moving_anymore {
-            self.moving_particles.remove(ind);
-        };   
+        image
+    }
 
-        Ok(self.moving_particles.len())
+    fn draw(&self){
+        println!("{}", self.render());
+    }
 
+    /// Where the floor is, if there is one.
+    fn floor(&self) -> isize {
+        self.max_y + 2
     }
 
-    fn run_single_sand_grain(&mut self) -> Result<(),()>{
-        self.moving_particles.push(self.sand_source.clone());
-        
-        while self.moving_particles.len() > 0 {
-            if let Err(_) = self.tick() {
-                return Err(());
-            }            
+    /// Where a grain at `(x, y)` falls next, `None` if it comes to rest.
+    fn next_position(&self, (x, y): Point) -> Option<Point> {
+        if self.has_floor && y == self.floor() - 1 {
+            return None;
         }
 
-        Ok(())
+        [(x, y + 1), (x - 1, y + 1), (x + 1, y + 1)].into_iter().find(|p| self.is_free(*p))
+    }
+
+    /// Drops a grain and returns where it came to rest. `None` once grains
+    /// fall into the abyss or the source is blocked. Up to where the previous
+    /// grain rested, a grain falls along the same path, so it starts from
+    /// the previous grain's last position instead of from the source.
+    fn drop_grain(&mut self) -> Option<Point> {
+        if !self.is_free(self.sand_source) {
+            return None;
+        }
+        if self.fall_path.is_empty() {
+            self.fall_path.push(self.sand_source);
+        }
+
+        loop {
+            let position = *self.fall_path.last()?;
+
+            match self.next_position(position) {
+                Some(next) if !self.has_floor && next.1 > self.max_y => return None,
+                Some(next) => self.fall_path.push(next),
+                None => {
+                    self.fall_path.pop();
+                    self.grid[position] = Some(FieldType::Sand);
+                    self.min_x = self.min_x.min(position.0);
+                    self.max_x = self.max_x.max(position.0);
+                    return Some(position);
+                }
+            }
+        }
     }
 }
 
 
 #[cfg(test)]
-
 mod test {
 
     use super::*;
@@ -191,6 +336,80 @@ mod test {
         assert_eq!(grains_processed, 24)        
     }
 
+    #[test]
+    fn drop_grain_01(){
+        let mut grid = Grid::new(&load(Some("assets/input_test_01.txt")), false);
+
+        assert_eq!(grid.drop_grain(), Some((500, 8)));
+        assert_eq!(grid.drop_grain(), Some((499, 8)));
+        assert_eq!(grid.fall_path, vec![(500, 0), (500, 1), (500, 2), (500, 3), (500, 4), (500, 5), (500, 6), (500, 7)]);
+
+        for _ in 0..3 { grid.drop_grain(); }
+        assert_eq!(grid.render().lines().take(10).collect::<Vec<&str>>(), vec![
+            "......+...",
+            "......~...",
+            "......~...",
+            "......~...",
+            "....#.~.##",
+            "....#.~.#.",
+            "..###.~.#.",
+            ".....~o.#.",
+            "....oooo#.",
+            "#########.",
+        ]);
+    }
+
+    #[test]
+    fn render_01(){
+        let mut grid = Grid::new(&load(Some("assets/input_test_01.txt")), true);
+        while grid.drop_grain().is_some() {}
+
+        let rendered = grid.render();
+        let lines = rendered.lines().collect::<Vec<&str>>();
+
+        assert_eq!((grid.min_x, grid.max_x), (490, 510));
+        assert_eq!(lines[0], "..........o..........");
+        assert_eq!(lines[9], ".ooo#########ooooooo.");
+        assert_eq!(lines[10], "ooooo.......ooooooooo");
+        assert_eq!(lines[11], "#####################");
+    }
+
+    #[test]
+    fn image_01(){
+        let mut grid = Grid::new(&load(Some("assets/input_test_01.txt")), false);
+        grid.drop_grain();
+
+        let mut ppm = vec![];
+        grid.image(1).write_ppm(&mut ppm).unwrap();
+        let header = "P6\n12 12\n255\n".len();
+        let pixel = |x: usize, y: usize| &ppm[header + (y * 12 + x) * 3..header + (y * 12 + x + 1) * 3];
+
+        assert!(ppm.starts_with(b"P6\n12 12\n255\n"));
+        assert_eq!(pixel(7, 0), image::SOURCE);
+        assert_eq!(pixel(7, 8), image::SAND);
+        assert_eq!(pixel(1, 9), image::ROCK);
+        assert_eq!(pixel(0, 0), image::AIR);
+        assert_eq!(numbered(Path::new("out/cave.png"), 100), Path::new("out/cave_000100.png"));
+    }
+
+    #[test]
+    fn near_zero_01(){
+        // deep enough for the sand to spread past x=0
+        let grains = resting_grains(&parse_paths("0,510 -> 1,510").unwrap(), true);
+
+        assert_eq!(grains, 512 * 512 - 2);
+    }
+
+    #[test]
+    fn parse_paths_01(){
+        assert_eq!(parse_paths("498,4 -> 498,6 -> 496,6\n503,4").unwrap(), vec![vec![(498, 4), (498, 6), (496, 6)], vec![(503, 4)]]);
+
+        let err = parse_paths("498,4 -> 498,6\n503,4 -> 502;4").unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 10, "502;4"));
+        let err = parse_paths("498,4 498,6").unwrap_err();
+        assert_eq!((err.column, err.expected.as_str()), (7, "'->'"));
+    }
+
     #[test]
     fn part02_01(){
         let grains_processed = part_02(Some("assets/input_test_01.txt"));
@@ -1,6 +1,56 @@
-use day_14::{part_01, part_02};
+use std::path::Path;
+use std::{env, process};
+
+use day_14::{animate, export_images, part_01, part_02};
+
+static USAGE: &str = "usage: day_14 [--animate [--floor] [--fps <n>] [--grains-per-frame <n>] [<input>]]
+       day_14 --export <image.ppm|image.png> [--floor] [--scale <n>] [--every <grains>] [<input>]";
+
+fn usage_error() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2);
+}
+
+fn number<T: std::str::FromStr>(arg: Option<&String>) -> T {
+    arg.and_then(|n| n.parse().ok()).unwrap_or_else(|| usage_error())
+}
 
 fn main() {
-    part_01(None);
-    part_02(None);
+    let args = env::args().skip(1).collect::<Vec<String>>();
+
+    let (animating, output) = match args.first().map(|a| a.as_str()) {
+        None => {
+            part_01(None);
+            part_02(None);
+            return;
+        },
+        Some("--animate") => (true, None),
+        Some("--export") => (false, Some(args.get(1).unwrap_or_else(|| usage_error()))),
+        Some(_) => usage_error(),
+    };
+
+    let mut has_floor = false;
+    let mut fps = 20;
+    let mut grains_per_frame = 1;
+    let mut scale = 1;
+    let mut every = None;
+    let mut path = None;
+    let mut rest = args[if animating { 1 } else { 2 }..].iter();
+
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--floor" => has_floor = true,
+            "--fps" if animating => fps = number(rest.next()),
+            "--grains-per-frame" if animating => grains_per_frame = number(rest.next()),
+            "--scale" if !animating => scale = number(rest.next()),
+            "--every" if !animating => every = Some(number(rest.next())),
+            p if !p.starts_with("--") && path.is_none() => path = Some(p),
+            _ => usage_error(),
+        }
+    }
+
+    match output {
+        Some(output) => export_images(path, has_floor, Path::new(output), scale, every),
+        None => animate(path, has_floor, grains_per_frame, fps),
+    }
 }
@@ -1,8 +1,12 @@
-use std::{hash::Hash, collections::HashMap, ops::RangeInclusive, time::Instant };
+mod render;
 
-use input_reader::read_lines;
+use std::{hash::Hash, collections::HashMap, ops::RangeInclusive, path::Path };
+
+use aoc_interval::IntervalSet;
+
+use aoc_input::{ParseError, Tokens};
+use aoc_solution::Solution;
 
-mod input_reader;
 
 #[derive(Debug, PartialEq, Hash, Clone)]
 struct Sensor {
@@ -12,137 +16,43 @@ struct Sensor {
 }
 
 impl Sensor {
-    fn new(line: &str)-> Self {
-        let (sc, bc) = get_coords_from_line(line);
-        Self { 
+    fn new(line: &str)-> Result<Self, ParseError> {
+        let (sc, bc) = get_coords_from_line(line)?;
+        Ok(Self { 
             pos: sc, 
             range: get_manhatten_distance(&sc, &bc), 
             beacon: bc 
-        }
+        })
     }
 }
 
-#[derive(Debug, PartialEq, Hash, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Hash, Eq, Clone, Copy, Default)]
 struct Coord (i32, i32);
 impl Coord {
     fn x(&self) -> i32 { self.0 }
     fn y(&self) -> i32 { self.1 }
 }
 
-#[derive(Clone, Debug, PartialEq)]
-struct MultiRange(Vec<RangeInclusive<i32>>);
-impl MultiRange {
-    fn new() -> Self {
-        Self(vec![])
-    }
-
-    fn add_range(&mut self, from:i32, to:i32){
-        
-        let f = if from < to { from } else { to };
-        let t = if to > from { to } else { from };
-
-        self.0.push(f..=t);
-        self.compact();
-
-    }
-
-    fn compact(&mut self){
-        let mut merged_ranges:Vec<RangeInclusive<i32>> = vec![];
-        
-        let mut ranges = self.0.clone();
-        ranges.sort_by(|a,b|{
-            a.start().cmp(b.end())
-        });
-
-        let mut r_it = ranges.iter().peekable();
-
-        while let Some(range) = r_it.next(){
-            if let Some(last_item) = merged_ranges.last_mut() {
-                if ranges_overlap_or_adjacent(range, &last_item){
-                    *last_item = combine_ranges(
-                        &last_item, &range.clone()
-                    );
-                    continue;                
-                }
-            } 
-
-            if let Some(&next_range) = r_it.peek() {
-                if ranges_overlap_or_adjacent(range, next_range) {
-                    merged_ranges.push(combine_ranges(range, next_range));
-                    r_it.next();
-                }
-                else {
-                    merged_ranges.push(range.clone());
-                }
-            } else {
-                merged_ranges.push(range.clone());
-            }
-        }
-
-        merged_ranges.sort_by(|a,b| {
-            a.start().cmp(b.start())
-        });
-
-        self.0 = merged_ranges;        
-    }
-
-    fn contains(&self, x: i32) -> bool {        
-        for r in self.clone().0 {
-            if r.contains(&x) {
-                return true;
-            }
-        }
-        false
-    }
-
-    fn not_in_range(&self, cmp: RangeInclusive<i32>) -> Vec<i32> {
-        let mut n_ir:Vec<i32> = vec![];
-        let mut i = *cmp.start();
-        let mut iter_ranges = self.0.clone().into_iter();
-        while i <= *cmp.end() {
-            if let Some(r) = &iter_ranges.next() {
-                while *r.start() > i {
-                    n_ir.push(i);
-                    i += 1;
-                }
-                i = r.end()+1;                
-            } else {
-                break;
-            }
-        }
-        n_ir
-
-    }
-
-    fn count(&self) -> i32 {
-        let mut count = 0;
-        println!("init count: {}", count);
-
-        for range in &self.0 {
-            count += range.end() - range.start() + 1;            
-            println!("count: {}", count);
-        }
-
-        count
-    }
-}
-
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug, Default)]
 struct Grid {
     sensors: HashMap<Coord, Sensor>,
     dimensions: (Coord, Coord)
         
 }
 impl Grid {
-    fn new(input: Vec<String>) -> Self {
+    fn new(input: Vec<String>) -> Result<Self, ParseError> {
 
         let mut grid = Self{
             sensors: HashMap::new(),
             dimensions: (Coord(0,0), Coord(0,0))
         };
 
-        for line in input.iter() {
-            let s = Sensor::new(line);
+        for (i, line) in input.iter().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let s = Sensor::new(line).map_err(|e| e.at_line(i + 1))?;
             let s_clone = s.clone();
             
             grid.add_sensor(s);            
@@ -150,7 +60,7 @@ impl Grid {
             Grid::update_dimensions_with_sensor(&mut grid, &s_clone);
         }
 
-        grid
+        Ok(grid)
     }
 
     fn add_sensor(&mut self, s: Sensor){
@@ -165,26 +75,20 @@ impl Grid {
         )
     }
 
-    fn get_mr_for_y(&self, y: i32) -> MultiRange {
-        let mut mr = MultiRange::new();        
-        
-        for (_, sensor) in self.sensors.clone() {           
-            if let Some(range) = get_known_empty_range_for_line_and_sensor(&sensor, y){
-                mr.add_range(*range.start(), *range.end());
-            }        
-        }
-
-        mr
+    fn get_mr_for_y(&self, y: i32) -> IntervalSet<i32> {
+        self.sensors
+            .values()
+            .filter_map(|sensor| get_known_empty_range_for_line_and_sensor(sensor, y))
+            .collect()
     }
 
 
+    /// Scans every row for a gap, slow on the real input but easy to trust.
     fn find_beacon(&self, xr: &RangeInclusive<i32>, yr: &RangeInclusive<i32>) -> Option<Coord>{
         for y in yr.clone() {
             // println!("Processing y: {}", y);
-            let mr = self.get_mr_for_y(y);
-            let n_ir = mr.not_in_range(xr.clone());
-            if n_ir.len() > 0 {
-                return Some(Coord(n_ir[0], y));
+            if let Some(gap) = self.get_mr_for_y(y).gaps(xr.clone()).next() {
+                return Some(Coord(*gap.start(), y));
             }
 
         }
@@ -192,98 +96,235 @@ impl Grid {
     }
 
 
+    /// A single uncovered point has to sit right outside the diamonds of the
+    /// sensors around it, so where the edges just outside two diamonds cross
+    /// is where to look. The edges are the lines `x + y = a` and `x - y = b`,
+    /// crossing at `((a + b) / 2, (a - b) / 2)`. On the border of the search
+    /// area the border itself boxes the point in, so where the edges cross
+    /// the border is checked too, and so are the corners, next to which no
+    /// edge has to pass at all.
+    fn find_beacon_by_boundaries(&self, xr: &RangeInclusive<i32>, yr: &RangeInclusive<i32>) -> Option<Coord>{
+        let mut ascending = vec![];
+        let mut descending = vec![];
+
+        for sensor in self.sensors.values() {
+            let (x, y, r) = (sensor.pos.x() as i64, sensor.pos.y() as i64, sensor.range as i64 + 1);
+            descending.extend([x + y - r, x + y + r]);
+            ascending.extend([x - y - r, x - y + r]);
+        }
+
+        let (x0, x1) = (*xr.start() as i64, *xr.end() as i64);
+        let (y0, y1) = (*yr.start() as i64, *yr.end() as i64);
+
+        let crossings = descending.iter()
+            .flat_map(|a| ascending.iter().map(move |b| (a, b)))
+            .filter(|(a, b)| (*a + *b) % 2 == 0)
+            .map(|(a, b)| ((a + b) / 2, (a - b) / 2));
+        let on_border = descending.iter()
+            .flat_map(|a| [(x0, a - x0), (x1, a - x1), (a - y0, y0), (a - y1, y1)])
+            .chain(ascending.iter().flat_map(|b| [(x0, x0 - b), (x1, x1 - b), (b + y0, y0), (b + y1, y1)]));
+        let corners = [(x0, y0), (x1, y0), (x0, y1), (x1, y1)];
+
+        crossings
+            .chain(on_border)
+            .chain(corners)
+            .filter(|(x, y)| (x0..=x1).contains(x) && (y0..=y1).contains(y))
+            .map(|(x, y)| Coord(x as i32, y as i32))
+            .find(|c| self.sensors.values().all(|s| get_manhatten_distance(&s.pos, c) > s.range))
+    }
+
     fn get_empty_count_for_y(&self, y: i32) -> i32{
         let mr = self.get_mr_for_y(y);
         let mut sensor_positions_present: Vec<i32> = vec![];
 
         for sensor in self.sensors.clone().values(){
-            if sensor.pos.y() == y && mr.contains(sensor.pos.x() ){
+            if sensor.pos.y() == y && mr.contains(sensor.pos.x()) {
                 sensor_positions_present.push(sensor.pos.x())
             }
 
-            if sensor.beacon.y() == y && mr.contains(sensor.beacon.x() ){ 
+            if sensor.beacon.y() == y && mr.contains(sensor.beacon.x()) {
                 sensor_positions_present.push(sensor.beacon.x())
             }
         }
         sensor_positions_present.sort();        
         sensor_positions_present.dedup();
-        let count = mr.count();
-        count - sensor_positions_present.len() as i32
+        mr.len() as i32 - sensor_positions_present.len() as i32
     }
     
 }
 
-pub fn part_01(path: Option<&str>) {
-    let started = Instant::now();
-    let path = path.unwrap_or("assets/input.txt");
-    let input = read_lines(path);
-    let grid = Grid::new(input);
+/// What the puzzle asks about: the row part 1 counts, the square part 2
+/// searches for the distress beacon and what its x is multiplied by for the
+/// tuning frequency. The example uses smaller numbers than the real input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Search {
+    pub row: i32,
+    pub bounds: RangeInclusive<i32>,
+    pub multiplier: i128,
+}
+
+impl Search {
+    pub const INPUT: Search = Search { row: 2000000, bounds: 0..=4000000, multiplier: 4000000 };
+    pub const EXAMPLE: Search = Search { row: 10, bounds: 0..=20, multiplier: 4000000 };
+
+    /// The values fitting the input at `path`, see [`Search::for_grid`].
+    pub fn detect(path: Option<&str>) -> Self {
+        Self::for_grid(&load(path))
+    }
+
+    /// The example's sensors all sit within a few dozen of the origin, the
+    /// real ones are spread over millions.
+    fn for_grid(grid: &Grid) -> Self {
+        let small = |c: &Coord| c.x().abs() <= 1000 && c.y().abs() <= 1000;
 
-    println!("Part 01 - {} positions cannot contain a beacon", grid.get_empty_count_for_y(2000000));
-    println!("Part 01 - Execution time {:?}", Instant::now()- started)
+        match grid.sensors.values().all(|s| small(&s.pos) && small(&s.beacon)) {
+            true => Self::EXAMPLE,
+            false => Self::INPUT,
+        }
+    }
 }
 
-pub fn part_02(path: Option<&str>) {
-    let started = Instant::now();
+fn load(path: Option<&str>) -> Grid {
     let path = path.unwrap_or("assets/input.txt");
-    let input = read_lines(path);
-    let grid = Grid::new(input);
-    let beacon = grid.find_beacon(&(0..=4000000), &(0..=4000000));
-    
-    if let Some(beacon) = beacon {
-        
-        let frequency:i128 = ((beacon.x() as i128)*4000000 + beacon.y() as i128) as i128;
-        println!("Part 02 - Coords of beacon are x: {}, y: {} frequency is: {}", beacon.x(), beacon.y(), frequency );
-        println!("Part 02 - Execution time {:?}", Instant::now()- started)
-    }    
+    let input = aoc_input::read_lines(path).unwrap_or_else(aoc_input::exit);
+    Grid::new(input).unwrap_or_else(aoc_input::exit)
 }
 
+pub fn part_01(path: Option<&str>) -> i32 {
+    let grid = load(path);
+    let search = Search::for_grid(&grid);
 
-fn get_known_empty_range_for_line_and_sensor(sensor: &Sensor, y: i32) -> Option<RangeInclusive<i32>> {
-    let distance_left = sensor.range - (y - sensor.pos.1).abs();    
-    if distance_left < 0 {
-        None
-    } else {
-        Some((sensor.pos.0 - distance_left)..=(sensor.pos.0 + distance_left ))
-    }
+    empty_count(&grid, &search)
 }
 
-fn sort_ranges<'a>( r1: &'a RangeInclusive<i32>, r2: &'a RangeInclusive<i32>) -> (RangeInclusive<i32>, RangeInclusive<i32>)
-{
-    let r1_min = r1.start();
-    let r2_min = r2.end();
-    if r1_min < r2_min { (r1.clone(), r2.clone()) } else { (r2.clone(), r1.clone()) }
+pub fn part_01_with(path: Option<&str>, search: &Search) -> i32 {
+    empty_count(&load(path), search)
 }
 
-fn combine_ranges(r1: &RangeInclusive<i32>, r2: &RangeInclusive<i32>) -> RangeInclusive<i32> {
-    if !ranges_overlap_or_adjacent(r1, r2) { panic!("Cannot combine ranges that don't overlap") }
+fn empty_count(grid: &Grid, search: &Search) -> i32 {
+    let empty_count = grid.get_empty_count_for_y(search.row);
 
-    let min = if r1.start() < r2.start() { *r1.start() } else { *r2.start() };
-    let max = if r1.end() > r2.end() { *r1.end() } else { *r2.end() };
+    println!("Part 01 - {} positions cannot contain a beacon in row {}", empty_count, search.row);
 
-   min..=max
+    empty_count
 }
 
-fn ranges_overlap_or_adjacent(r1: &RangeInclusive<i32>, r2: &RangeInclusive<i32>) -> bool {
-    let (r1, r2) = sort_ranges(r1, r2);
-    *r1.end() >= *r2.start() - 1
+pub fn part_02(path: Option<&str>) -> i128 {
+    let grid = load(path);
+    let search = Search::for_grid(&grid);
+
+    print_frequency(&grid, &search, false)
 }
 
-fn get_coords_from_line(line:&str) -> (Coord, Coord){
+/// Part 2 the slow way, scanning every row of the search area. Kept to
+/// compare against.
+pub fn part_02_by_row_scan(path: Option<&str>) -> i128 {
+    let grid = load(path);
+    let search = Search::for_grid(&grid);
 
-    let mut numbers:Vec<i32> = vec![];
+    print_frequency(&grid, &search, true)
+}
 
-    for item in line.split([',',':','=']) {
-        if let Ok(num) = item.parse::<i32>() {
-            numbers.push(num);
-        }
-    };
+pub fn part_02_with(path: Option<&str>, search: &Search, scan_rows: bool) -> i128 {
+    print_frequency(&load(path), search, scan_rows)
+}
+
+fn print_frequency(grid: &Grid, search: &Search, scan_rows: bool) -> i128 {
+    let frequency = tuning_frequency(grid, search, scan_rows);
+    println!("Part 02 - frequency is: {}", frequency );
+
+    frequency
+}
+
+fn tuning_frequency(grid: &Grid, search: &Search, scan_rows: bool) -> i128 {
+    let bounds = &search.bounds;
+    let beacon = match scan_rows {
+        true => grid.find_beacon(bounds, bounds),
+        false => grid.find_beacon_by_boundaries(bounds, bounds),
+    }.expect("no distress beacon found");
+
+    (beacon.x() as i128)*search.multiplier + beacon.y() as i128
+}
+
+/// Prints the sensors, beacons and the cells they cover within `xr` and
+/// `yr` like the puzzle does.
+pub fn print_coverage(path: Option<&str>, xr: &RangeInclusive<i32>, yr: &RangeInclusive<i32>) {
+    println!("{}", render::render(&load(path), xr, yr));
+}
+
+/// Saves the diamonds of the sensors across the search area as an image
+/// about `size` pixels a side, the distress beacon marked. PNG if `output`
+/// ends in `.png`, PPM otherwise.
+pub fn export_coverage(path: Option<&str>, search: &Search, output: &Path, size: usize) {
+    let grid = load(path);
+    let beacon = grid.find_beacon_by_boundaries(&search.bounds, &search.bounds);
+
+    render::coverage_image(&grid, &search.bounds, size, beacon)
+        .save(output)
+        .unwrap_or_else(|err| aoc_input::exit(format!("{}: {}", output.display(), err)));
+
+    match beacon {
+        Some(beacon) => println!("Distress beacon at x={}, y={}", beacon.x(), beacon.y()),
+        None => println!("No distress beacon within the search area"),
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Default)]
+pub struct AOC_2022_15 {
+    grid: Grid
+}
+impl AOC_2022_15 {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Solution for AOC_2022_15 {
+    type Part1 = i32;
+    type Part2 = i128;
+
+    fn name(&self) -> (usize, usize) {
+        (2022,15)
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+        self.grid = Grid::new(input.lines().map(|l| l.to_string()).collect())?;
+        Ok(())
+    }
+
+    fn part1(&self) -> i32 {
+        self.grid.get_empty_count_for_y(Search::for_grid(&self.grid).row)
+    }
+
+    fn part2(&self) -> i128 {
+        tuning_frequency(&self.grid, &Search::for_grid(&self.grid), false)
+    }
+}
+
+fn get_known_empty_range_for_line_and_sensor(sensor: &Sensor, y: i32) -> Option<RangeInclusive<i32>> {
+    let distance_left = sensor.range - (y - sensor.pos.1).abs();    
+    if distance_left < 0 {
+        None
+    } else {
+        Some((sensor.pos.0 - distance_left)..=(sensor.pos.0 + distance_left ))
+    }
+}
 
-    if numbers.len() != 4 {
-        panic!("Could not find 4 numbers in line but {}", numbers.len());
+fn get_coords_from_line(line:&str) -> Result<(Coord, Coord), ParseError>{
+    let mut tokens = Tokens::new(line);
+
+    tokens.keyword("Sensor")?;
+    tokens.keyword("at")?;
+    let sensor = Coord(tokens.assignment("x", "a number")?, tokens.assignment("y", "a number")?);
+
+    for keyword in ["closest", "beacon", "is", "at"] {
+        tokens.keyword(keyword)?;
     }
+    let beacon = Coord(tokens.assignment("x", "a number")?, tokens.assignment("y", "a number")?);
+    tokens.end()?;
 
-    (Coord(numbers[0], numbers[1]), Coord(numbers[2], numbers[3]))
+    Ok((sensor, beacon))
 }
 
 fn get_manhatten_distance(p1: &Coord, p2: &Coord) -> i32 {
@@ -293,14 +334,12 @@ fn get_manhatten_distance(p1: &Coord, p2: &Coord) -> i32 {
 
 #[cfg(test)]
 mod test {
-    use crate::input_reader::read_lines;
-
     use super::*;
 
     #[test]
     fn get_coords_from_line_01(){
         let input = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15";
-        let output = get_coords_from_line(input);
+        let output = get_coords_from_line(input).unwrap();
 
         let s_exp = Coord(2,18);
         let b_exp = Coord(-2,15);
@@ -310,6 +349,15 @@ mod test {
 
     }
 
+    #[test]
+    fn get_coords_from_line_02(){
+        let err = get_coords_from_line("Sensor at x=2, y=18: closest beacon is at x=-2, y=").unwrap_err();
+        assert_eq!((err.column, err.expected.as_str(), err.found.as_str()), (51, "a number", ""));
+
+        let err = Grid::new(vec!["Sensor at x=2, y=18: closest beacon is at x=-2, y=15 ".to_string(), "Sensor at 2, 18".to_string()]).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 11));
+    }
+
     #[test]
     fn get_manhattan_distance_01(){
         let from = Coord(1,-5);
@@ -321,50 +369,36 @@ mod test {
 
     #[test]
     fn multi_range_01(){
-        let mut mr = MultiRange::new();
-        mr.add_range(0,5);
-        mr.add_range(3, 6);
+        let mr = IntervalSet::from_iter([0..=5, 3..=6]);
 
-        assert_eq!(mr.0.len(), 1);
-        assert_eq!(mr.0[0], 0..=6);
+        assert_eq!(mr.ranges(), &[0..=6]);
     }
 
     #[test]
     fn multi_range_02(){
-        let mut mr = MultiRange::new();
-        mr.add_range(0,5);
-        mr.add_range(7, 12);
+        let mr = IntervalSet::from_iter([0..=5, 7..=12]);
 
-        assert_eq!(mr.0.len(), 2);
-        assert_eq!(mr.0[0], 0..=5);
-        assert_eq!(mr.0[1], 7..=12);
+        assert_eq!(mr.ranges(), &[0..=5, 7..=12]);
     }
 
     #[test]
     fn multi_range_03(){
-        let mut mr = MultiRange::new();
-        mr.add_range(12,6);
-        mr.add_range(2, 5);
+        let mr = IntervalSet::from_iter([6..=12, 2..=5]);
 
-        assert_eq!(mr.0.len(), 1);
-        assert_eq!(mr.0[0], 2..=12);
+        assert_eq!(mr.ranges(), &[2..=12]);
     }
 
     #[test]
     fn multi_range_04(){
-        let mut mr = MultiRange::new();
-        mr.add_range(1,5);
-        mr.add_range(7, 10);
-        mr.add_range(4,8);
+        let mr = IntervalSet::from_iter([1..=5, 7..=10, 4..=8]);
 
-        assert_eq!(mr.0.len(), 1);
-        assert_eq!(mr.0[0], 1..=10);
+        assert_eq!(mr.ranges(), &[1..=10]);
     }
 
     #[test]
     fn grid_01(){
-        let input = read_lines("assets/input_test_01.txt");
-        let grid = Grid::new(input);
+        let input = aoc_input::read_lines("assets/input_test_01.txt").unwrap();
+        let grid = Grid::new(input).unwrap();
         let res = grid.get_empty_count_for_y(10);
 
         assert_eq!(res, 26);
@@ -372,8 +406,8 @@ mod test {
 
     #[test]
     fn grid_02(){
-        let input = read_lines("assets/input_test_01.txt");
-        let grid = Grid::new(input);
+        let input = aoc_input::read_lines("assets/input_test_01.txt").unwrap();
+        let grid = Grid::new(input).unwrap();
 
         let res = grid.get_empty_count_for_y(9);
 
@@ -382,8 +416,8 @@ mod test {
 
     #[test]
     fn grid_03(){
-        let input = read_lines("assets/input_test_01.txt");
-        let grid = Grid::new(input);
+        let input = aoc_input::read_lines("assets/input_test_01.txt").unwrap();
+        let grid = Grid::new(input).unwrap();
 
         let res = grid.get_empty_count_for_y(11);
 
@@ -395,7 +429,7 @@ mod test {
         let r1 = 0..=5;
         let r2 = 5..=6;
 
-        assert!(ranges_overlap_or_adjacent(&r1, &r2));
+        assert_eq!(IntervalSet::from_iter([r1, r2]).ranges().len(), 1);
     }
 
     #[test]
@@ -403,7 +437,7 @@ mod test {
         let r2 = 0..=4;
         let r1 = 5..=6;
 
-        assert!(ranges_overlap_or_adjacent(&r1, &r2));
+        assert_eq!(IntervalSet::from_iter([r1, r2]).ranges().len(), 1);
     }
 
     #[test]
@@ -411,47 +445,74 @@ mod test {
         let r1 = -2..=1;
         let r2 = -1..=5;
 
-        assert!(ranges_overlap_or_adjacent(&r1, &r2));
+        assert_eq!(IntervalSet::from_iter([r1, r2]).ranges().len(), 1);
     }
 
     #[test]
     fn find_empty_coord_01(){
-        let input = read_lines("assets/input_test_01.txt");
-        let grid = Grid::new(input);
+        let input = aoc_input::read_lines("assets/input_test_01.txt").unwrap();
+        let grid = Grid::new(input).unwrap();
 
         let res = grid.find_beacon(&(0..=20), &(0..=20));
 
         assert_eq!(res, Some(Coord(14,11)));
     }
 
+    #[test]
+    fn find_empty_coord_02(){
+        let input = aoc_input::read_lines("assets/input_test_01.txt").unwrap();
+        let grid = Grid::new(input).unwrap();
+
+        assert_eq!(grid.find_beacon_by_boundaries(&(0..=20), &(0..=20)), Some(Coord(14,11)));
+
+        let corner = Grid::new(vec!["Sensor at x=0, y=0: closest beacon is at x=5, y=0".to_string()]).unwrap();
+        assert_eq!(corner.find_beacon_by_boundaries(&(0..=3), &(0..=3)), Some(Coord(3,3)));
+        assert_eq!(corner.find_beacon(&(0..=3), &(0..=3)), Some(Coord(3,3)));
+        assert_eq!(corner.find_beacon_by_boundaries(&(0..=2), &(0..=2)), None);
+
+        let edge = Grid::new(vec![
+            "Sensor at x=2, y=0: closest beacon is at x=5, y=0".to_string(),
+            "Sensor at x=-2, y=4: closest beacon is at x=-5, y=4".to_string(),
+            "Sensor at x=4, y=4: closest beacon is at x=8, y=4".to_string(),
+        ]).unwrap();
+        assert_eq!(edge.find_beacon(&(0..=4), &(0..=4)), Some(Coord(0,2)));
+        assert_eq!(edge.find_beacon_by_boundaries(&(0..=4), &(0..=4)), Some(Coord(0,2)));
+    }
+
+    #[test]
+    fn search_01(){
+        assert_eq!(Search::detect(Some("assets/input_test_01.txt")), Search::EXAMPLE);
+        assert_eq!(Search::detect(Some("assets/input.txt")), Search::INPUT);
+
+        assert_eq!(part_01(Some("assets/input_test_01.txt")), 26);
+        assert_eq!(part_02(Some("assets/input_test_01.txt")), 56000011);
+        assert_eq!(part_02_by_row_scan(Some("assets/input_test_01.txt")), 56000011);
+
+        let search = Search { row: 11, bounds: 0..=20, multiplier: 100 };
+        assert_eq!(part_01_with(Some("assets/input_test_01.txt"), &search), 27);
+        assert_eq!(part_02_with(Some("assets/input_test_01.txt"), &search, false), 1411);
+    }
+
     #[test]
     fn compact_01(){
-        let mut mr = MultiRange(
-            vec![
+        let mr = IntervalSet::from_iter(vec![
                 0..=5,
                 7..=10,
                 6..=8
-            ]
-        );
-        mr.compact();
-        
+            ]);
 
-        assert_eq!(mr.0.len(), 1);
+        assert_eq!(mr.ranges().len(), 1);
     }
 
     #[test]
     fn compact_02(){
-        let mut mr = MultiRange(
-            vec![
+        let mr = IntervalSet::from_iter(vec![
                 -2..=14,
                 12..=12,
                 15..=24,
                 14..=18
-            ]
-        );
-        mr.compact();
-        
+            ]);
 
-        assert_eq!(mr.0.len(), 1);
+        assert_eq!(mr.ranges().len(), 1);
     }
 }
\ No newline at end of file
@@ -1,6 +1,87 @@
-use day_15_alt::{part_01, part_02};
+use std::path::Path;
+use std::{env, process};
+
+use day_15_alt::{export_coverage, part_01_with, part_02_with, print_coverage, Search};
+
+static USAGE: &str = "usage: day_15_alt [--row <y>] [--bounds <min>..=<max>] [--multiplier <n>] [--row-scan] [<input>]
+       day_15_alt --render [--x <min>..=<max>] [--y <min>..=<max>] [<input>]
+       day_15_alt --image <image.ppm|image.png> [--size <pixels>] [--bounds <min>..=<max>] [<input>]
+
+Without flags the row, bounds and multiplier of the puzzle are used, or those
+of the example when the input looks like it. --render draws the search area
+unless --x or --y narrow it down, and needs them once that is wider or higher
+than 400 cells.";
+
+/// The widest or highest area --render draws, the real search area would
+/// never finish printing.
+const RENDER_LIMIT: i64 = 400;
+
+fn usage_error() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2);
+}
+
+fn number<T: std::str::FromStr>(arg: Option<&str>) -> T {
+    arg.and_then(|n| n.parse().ok()).unwrap_or_else(|| usage_error())
+}
+
+fn range(arg: Option<&str>) -> std::ops::RangeInclusive<i32> {
+    let (min, max) = arg.and_then(|r| r.split_once("..=")).unwrap_or_else(|| usage_error());
+    number(Some(min))..=number(Some(max))
+}
 
 fn main() {
-    part_01(None);
-    part_02(None);
+    let args = env::args().skip(1).collect::<Vec<String>>();
+
+    let mut row = None;
+    let mut bounds = None;
+    let mut multiplier = None;
+    let mut scan_rows = false;
+    let mut rendering = false;
+    let (mut xr, mut yr) = (None, None);
+    let mut image = None;
+    let mut size = 800;
+    let mut path = None;
+    let mut rest = args.iter().map(|a| a.as_str());
+
+    while let Some(arg) = rest.next() {
+        match arg {
+            "--row" => row = Some(number(rest.next())),
+            "--bounds" => bounds = Some(range(rest.next())),
+            "--multiplier" => multiplier = Some(number(rest.next())),
+            "--row-scan" => scan_rows = true,
+            "--render" => rendering = true,
+            "--x" => xr = Some(range(rest.next())),
+            "--y" => yr = Some(range(rest.next())),
+            "--image" => image = Some(rest.next().unwrap_or_else(|| usage_error())),
+            "--size" => size = number(rest.next()),
+            p if !p.starts_with("--") && path.is_none() => path = Some(p),
+            _ => usage_error(),
+        }
+    }
+
+    let detected = Search::detect(path);
+    let search = Search {
+        row: row.unwrap_or(detected.row),
+        bounds: bounds.unwrap_or(detected.bounds),
+        multiplier: multiplier.unwrap_or(detected.multiplier),
+    };
+
+    match (rendering, image) {
+        (true, None) => {
+            let (xr, yr) = (xr.unwrap_or(search.bounds.clone()), yr.unwrap_or(search.bounds));
+            let span = |r: &std::ops::RangeInclusive<i32>| *r.end() as i64 - *r.start() as i64 + 1;
+            if span(&xr) > RENDER_LIMIT || span(&yr) > RENDER_LIMIT {
+                eprintln!("--render draws at most {} cells a side, narrow it down with --x and --y", RENDER_LIMIT);
+                process::exit(2);
+            }
+            print_coverage(path, &xr, &yr);
+        },
+        (false, Some(image)) => export_coverage(path, &search, Path::new(image), size),
+        (false, None) => {
+            part_01_with(path, &search);
+            part_02_with(path, &search, scan_rows);
+        },
+        (true, Some(_)) => usage_error(),
+    }
 }
@@ -0,0 +1,143 @@
+use std::ops::RangeInclusive;
+
+use aoc_image::{Image, Rgb};
+
+use crate::{get_manhatten_distance, Coord, Grid, Sensor};
+
+const UNCOVERED: Rgb = [16, 16, 24];
+const EDGE: Rgb = [200, 200, 200];
+const SENSOR: Rgb = [255, 255, 255];
+const DISTRESS_BEACON: Rgb = [255, 48, 48];
+/// Handed out to the sensors in turn, so neighbouring diamonds can be told
+/// apart.
+const DIAMONDS: [Rgb; 6] = [
+    [48, 80, 128],
+    [48, 112, 80],
+    [112, 64, 112],
+    [128, 96, 48],
+    [48, 104, 112],
+    [96, 96, 48],
+];
+
+fn covers(sensor: &Sensor, c: &Coord) -> bool {
+    get_manhatten_distance(&sensor.pos, c) <= sensor.range
+}
+
+/// The window like the puzzle draws it: `S` for sensors, `B` for beacons,
+/// `#` where a sensor rules out a beacon and `.` everywhere else.
+pub fn render(grid: &Grid, xr: &RangeInclusive<i32>, yr: &RangeInclusive<i32>) -> String {
+    yr.clone()
+        .map(|y| {
+            xr.clone()
+                .map(|x| {
+                    let c = Coord(x, y);
+                    let mut sensors = grid.sensors.values();
+
+                    if sensors.clone().any(|s| s.pos == c) {
+                        'S'
+                    } else if sensors.clone().any(|s| s.beacon == c) {
+                        'B'
+                    } else if sensors.any(|s| covers(s, &c)) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// The square `bounds` squeezed into about `size` pixels a side, every
+/// pixel standing for a square of cells and coloured like the sensor
+/// covering its middle, with the edges of the diamonds lighter. Areas small
+/// enough are drawn a cell per `size / width` pixels instead. The sensors
+/// are white and `beacon`, if there is one, gets a red marker big enough to
+/// find on the real input.
+pub fn coverage_image(grid: &Grid, bounds: &RangeInclusive<i32>, size: usize, beacon: Option<Coord>) -> Image {
+    let span = (*bounds.end() as i64 - *bounds.start() as i64 + 1).max(1);
+    let size = size.max(1) as i64;
+    let cells_per_pixel = (span + size - 1) / size;
+    let width = (span + cells_per_pixel - 1) / cells_per_pixel;
+    let to_pixel = |v: i32| ((v as i64 - *bounds.start() as i64) / cells_per_pixel) as usize;
+
+    let mut sensors = grid.sensors.values().collect::<Vec<&Sensor>>();
+    sensors.sort_by_key(|s| (s.pos.y(), s.pos.x()));
+
+    let mut image = Image::new(width as usize, width as usize, (size / width) as usize, UNCOVERED);
+    for py in 0..width {
+        for px in 0..width {
+            let middle = |p: i64| (*bounds.start() as i64 + p * cells_per_pixel + cells_per_pixel / 2) as i32;
+            let c = Coord(middle(px), middle(py));
+
+            let covering = sensors.iter().enumerate().find(|(_, s)| covers(s, &c));
+            if let Some((i, sensor)) = covering {
+                let edge = (sensor.range - get_manhatten_distance(&sensor.pos, &c)) as i64 <= cells_per_pixel / 2;
+                image.set_cell(px as usize, py as usize, if edge { EDGE } else { DIAMONDS[i % DIAMONDS.len()] });
+            }
+        }
+    }
+
+    for sensor in sensors.iter().filter(|s| bounds.contains(&s.pos.x()) && bounds.contains(&s.pos.y())) {
+        image.set_cell(to_pixel(sensor.pos.x()), to_pixel(sensor.pos.y()), SENSOR);
+    }
+
+    if let Some(beacon) = beacon {
+        let (bx, by) = (to_pixel(beacon.x()) as i64, to_pixel(beacon.y()) as i64);
+        let radius = (width / 100).max(2);
+        let thickness = (radius / 4).max(1);
+        for dy in -radius..=radius {
+            for dx in -radius..=radius {
+                let (x, y) = (bx + dx, by + dy);
+                let ring = dx.abs().max(dy.abs()) > radius - thickness || (dx, dy) == (0, 0);
+                if ring && (0..width).contains(&x) && (0..width).contains(&y) {
+                    image.set_cell(x as usize, y as usize, DISTRESS_BEACON);
+                }
+            }
+        }
+    }
+
+    image
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn example() -> Grid {
+        Grid::new(aoc_input::read_lines("assets/input_test_01.txt").unwrap()).unwrap()
+    }
+
+    #[test]
+    fn render_01() {
+        let grid = example();
+        let window = render(&grid, &(-2..=25), &(9..=11));
+
+        assert_eq!(window, [
+            ".#########################..",
+            "####B######################.",
+            "##S#############.###########",
+        ].join("\n"));
+        assert_eq!(render(&grid, &(-10..=30), &(10..=10)).matches('#').count(), 26);
+    }
+
+    #[test]
+    fn coverage_image_01() {
+        let grid = example();
+        let image = coverage_image(&grid, &(0..=20), 42, Some(Coord(14, 11)));
+        assert_eq!(image.cells(), (21, 21));
+
+        let mut ppm = vec![];
+        image.write_ppm(&mut ppm).unwrap();
+        let pixels = &ppm[b"P6\n42 42\n255\n".len()..];
+        let pixel = |x: usize, y: usize| &pixels[(y * 2 * 42 + x * 2) * 3..][..3];
+
+        assert_eq!(pixel(14, 11), DISTRESS_BEACON);
+        assert_eq!(pixel(2, 18), SENSOR);
+        assert_ne!(pixel(0, 0), UNCOVERED);
+
+        let squeezed = coverage_image(&grid, &(0..=4000000), 400, None);
+        assert_eq!(squeezed.cells(), (400, 400));
+    }
+}
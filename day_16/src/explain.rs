@@ -0,0 +1,214 @@
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+
+use aoc_pathfinder::Pathfinder;
+use serde_json::{json, Value};
+
+use crate::{tunnels, Valve, ValveGraph};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    MoveTo((char, char)),
+    OpenValve((char, char)),
+    /// Nothing left worth opening, stay at the valve.
+    Wait((char, char)),
+}
+
+impl Action {
+    fn kind(&self) -> &'static str {
+        match self {
+            Action::MoveTo(_) => "move_to",
+            Action::OpenValve(_) => "open_valve",
+            Action::Wait(_) => "wait",
+        }
+    }
+
+    fn valve(&self) -> (char, char) {
+        match self {
+            Action::MoveTo(valve) | Action::OpenValve(valve) | Action::Wait(valve) => *valve,
+        }
+    }
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (a, b) = self.valve();
+        match self {
+            Action::MoveTo(_) => write!(f, "move to {}{}", a, b),
+            Action::OpenValve(_) => write!(f, "open {}{}", a, b),
+            Action::Wait(_) => write!(f, "wait at {}{}", a, b),
+        }
+    }
+}
+
+/// One minute of the winning schedule.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Minute {
+    pub minute: u8,
+    /// What each actor does this minute, you first.
+    pub actions: Vec<Action>,
+    /// Pressure released this minute by the valves opened before it.
+    pub flow: u16,
+    /// Pressure released so far, this minute included.
+    pub pressure_released: u16,
+}
+
+impl ValveGraph {
+    /// The valves to open, in order, for the best result opening only valves
+    /// from `allowed`.
+    fn best_route(&self, time: u8, allowed: u64) -> Vec<(char, char)> {
+        let (end, _) = self.best_end(time, allowed);
+        let path = self
+            .pathfinder(time, allowed)
+            .dijkstra([self.initial_state(time)], |state| *state == end)
+            .expect("the best end state was reached by the same search");
+
+        path.states[1..].iter().map(|state| self.names[state.current_positon]).collect()
+    }
+}
+
+/// Valves passed on the way from `from` to `to`, `to` included.
+fn shortest_path(valves: &[Valve], from: (char, char), to: (char, char)) -> Vec<(char, char)> {
+    let path = Pathfinder::new(|name: &(char, char)| tunnels(valves, name))
+        .bfs([from], |name| *name == to)
+        .expect("the best route only visits reachable valves");
+
+    path.states[1..].to_vec()
+}
+
+fn actions_for_route(valves: &[Valve], route: &[(char, char)], time: u8) -> Vec<Action> {
+    let mut position = ('A', 'A');
+    let mut actions = vec![];
+
+    for valve in route {
+        actions.extend(shortest_path(valves, position, *valve).into_iter().map(Action::MoveTo));
+        actions.push(Action::OpenValve(*valve));
+        position = *valve;
+    }
+    actions.resize(time as usize, Action::Wait(position));
+
+    actions
+}
+
+/// Reconstructs the schedule behind [`crate::max_pressure`] or, with the
+/// elephant, [`crate::max_pressure_with_elephant`].
+pub fn explain(valves: &[Valve], time: u8, with_elephant: bool) -> Vec<Minute> {
+    let graph = ValveGraph::new(valves);
+    let allowed = match with_elephant {
+        true => {
+            let (mine, elephants) = graph.best_split(time);
+            vec![mine, elephants]
+        }
+        false => vec![graph.all_valves()],
+    };
+    let schedules = allowed
+        .iter()
+        .map(|allowed| actions_for_route(valves, &graph.best_route(time, *allowed), time))
+        .collect::<Vec<Vec<Action>>>();
+
+    let flow_rates = valves.iter().map(|v| (v.name, v.flow_rate)).collect::<HashMap<(char, char), u16>>();
+    let mut flow = 0;
+    let mut pressure_released = 0;
+
+    (0..time as usize)
+        .map(|i| {
+            let actions = schedules.iter().map(|schedule| schedule[i]).collect::<Vec<Action>>();
+            let minute = Minute { minute: i as u8 + 1, actions, flow, pressure_released: pressure_released + flow };
+
+            pressure_released += flow;
+            for action in minute.actions.iter() {
+                if let Action::OpenValve(valve) = action {
+                    flow += flow_rates[valve];
+                }
+            }
+
+            minute
+        })
+        .collect()
+}
+
+fn actors(minutes: &[Minute]) -> &'static [&'static str] {
+    match minutes.first().map(|m| m.actions.len()) {
+        Some(2) => &["you", "elephant"],
+        _ => &["you"],
+    }
+}
+
+pub fn render_explanation(minutes: &[Minute]) -> String {
+    let actors = actors(minutes);
+    let mut header = format!("{:>6}", "Minute");
+    for actor in actors {
+        header.push_str(&format!("  {:<12}", actor));
+    }
+    let mut lines = vec![format!("{}  {:>5}  {:>8}", header, "Flow", "Released")];
+
+    for minute in minutes {
+        let mut line = format!("{:>6}", minute.minute);
+        for action in minute.actions.iter() {
+            line.push_str(&format!("  {:<12}", action.to_string()));
+        }
+        lines.push(format!("{}  {:>5}  {:>8}", line, minute.flow, minute.pressure_released));
+    }
+
+    lines.join("\n")
+}
+
+pub fn explanation_json(minutes: &[Minute]) -> Value {
+    let actors = actors(minutes);
+    let minutes_json = minutes
+        .iter()
+        .map(|minute| {
+            let actions = minute
+                .actions
+                .iter()
+                .zip(actors)
+                .map(|(action, actor)| {
+                    let (a, b) = action.valve();
+                    json!({ "actor": actor, "action": action.kind(), "valve": format!("{}{}", a, b) })
+                })
+                .collect::<Vec<Value>>();
+
+            json!({
+                "minute": minute.minute,
+                "actions": actions,
+                "flow": minute.flow,
+                "pressure_released": minute.pressure_released,
+            })
+        })
+        .collect::<Vec<Value>>();
+
+    json!({
+        "pressure_released": minutes.last().map_or(0, |m| m.pressure_released),
+        "minutes": minutes_json,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{max_pressure, max_pressure_with_elephant, parse_valves, TIME_ALONE, TIME_WITH_ELEPHANT};
+
+    #[test]
+    fn explain_01() {
+        let valves = parse_valves(&aoc_input::read_file("assets/input_test_01.txt").unwrap()).unwrap();
+        let minutes = explain(&valves, TIME_ALONE, false);
+
+        assert_eq!(minutes.len(), 30);
+        assert_eq!(minutes[0].actions, vec![Action::MoveTo(('D', 'D'))]);
+        assert_eq!(minutes[1].actions, vec![Action::OpenValve(('D', 'D'))]);
+        assert_eq!((minutes[2].flow, minutes[2].pressure_released), (20, 20));
+        assert_eq!(minutes[29].pressure_released, max_pressure(&valves, TIME_ALONE));
+    }
+
+    #[test]
+    fn explain_02() {
+        let valves = parse_valves(&aoc_input::read_file("assets/input_test_01.txt").unwrap()).unwrap();
+        let minutes = explain(&valves, TIME_WITH_ELEPHANT, true);
+        let json = explanation_json(&minutes);
+
+        assert_eq!(minutes[25].pressure_released, max_pressure_with_elephant(&valves, TIME_WITH_ELEPHANT));
+        assert_eq!(json["pressure_released"], 1707);
+        assert_eq!(json["minutes"][0]["actions"][1]["actor"], "elephant");
+        assert!(render_explanation(&minutes).starts_with("Minute  you           elephant       Flow  Released"));
+    }
+}
@@ -1,288 +1,416 @@
-use std::{collections::{ HashSet, BTreeSet }, hash::Hash, time::Instant };
+mod explain;
 
-use input_reader::read_lines;
+use std::collections::HashMap;
 
-mod input_reader;
+use aoc_input::{ParseError, Tokens};
+use aoc_pathfinder::Pathfinder;
+use aoc_solution::Solution;
 
-static MAX_TIME_ELAPSED:u8 = 30;
+pub use explain::{explain, explanation_json, render_explanation, Action, Minute};
+
+
+/// Minutes until the volcano erupts when you're on your own.
+pub const TIME_ALONE:u8 = 30;
+/// Minutes left after spending 4 teaching the elephant.
+pub const TIME_WITH_ELEPHANT:u8 = 26;
+/// The elephant's search keeps the best pressure for every set of valves
+/// with flow, 2^20 of them are still only a few megabytes.
+pub const MAX_VALVES_WITH_FLOW:usize = 20;
+/// Pressure is counted in a `u16`, this much flow can run for any time
+/// budget a `u8` holds without overflowing it.
+pub const MAX_TOTAL_FLOW:u32 = u16::MAX as u32 / u8::MAX as u32;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-struct Valve  {
+pub struct Valve  {
     name: (char, char),
     flow_rate: u16,
     possible_destinations: Vec<(char,char)>
 }
 impl Valve {
-    fn parse_from_line(line: String) -> Self {
-        let split_line= line.split(' ').collect::<Vec<&str>>();
-        let name_str = split_line[1].to_string();
-        let mut name_iter = name_str.chars();
-        let name = (name_iter.next().unwrap(), name_iter.next().unwrap());
-        let flow_rate = *&split_line[4]
-            .split('=')
-            .map(|s| { s[..s.len()-1].to_string() })
-            .collect::<Vec<String>>()[1]
-            .parse::<u16>()
-            .unwrap();
-
-        let possible_destinations = (split_line[9..])
-        .iter()
-        .map(|&raw_name| {
-            let mut i = raw_name.chars();
-            (i.next().unwrap(), i.next().unwrap()) 
-        })
-        .collect::<Vec<(char,char)>>();
+    fn parse_from_line(line: &str) -> Result<Self, ParseError> {
+        let mut tokens = Tokens::new(line);
 
-        Self {
+        tokens.keyword("Valve")?;
+        let name = parse_valve_name(&mut tokens)?;
+        for keyword in ["has", "flow"] {
+            tokens.keyword(keyword)?;
+        }
+        let flow_rate = tokens.assignment::<u16>("rate", "a flow rate")?;
+        tokens.one_of(&["tunnels", "tunnel"])?;
+        tokens.one_of(&["lead", "leads"])?;
+        tokens.keyword("to")?;
+        tokens.one_of(&["valves", "valve"])?;
+
+        let mut possible_destinations = vec![parse_valve_name(&mut tokens)?];
+        while !tokens.is_empty() {
+            possible_destinations.push(parse_valve_name(&mut tokens)?);
+        }
+
+        Ok(Self {
             name,
             flow_rate,
             possible_destinations,
-        }
+        })
     }
 }
 
-#[derive(Debug, Clone, Eq)]
-// #[derive(Debug, Clone, PartialEq, Hash, Eq)]
-pub struct VolcanoState {
-    open_valves: BTreeSet<(char,char)>,
-    current_positon: (char,char),
-    current_flow: u16,
-    pressure_released: u16,
-    time_left: u8
+fn parse_valve_name(tokens: &mut Tokens) -> Result<(char, char), ParseError> {
+    tokens.parse_with("a two letter valve name", |name| {
+        match name.chars().collect::<Vec<char>>()[..] {
+            [a, b] => Some((a, b)),
+            _ => None,
+        }
+    })
 }
-impl Hash for VolcanoState {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        // self.open_valves.hash(state);
-        self.current_positon.hash(state);
-        self.current_flow.hash(state);
-        self.pressure_released.hash(state);
-        self.time_left.hash(state);
+
+/// The valves of the scan, at most [`MAX_VALVES_WITH_FLOW`] of them with a
+/// flow rate adding up to at most [`MAX_TOTAL_FLOW`].
+pub fn parse_valves(input: &str) -> Result<Vec<Valve>, ParseError> {
+    let valves = aoc_input::parse_lines(input, Valve::parse_from_line)?;
+    let lines = input.lines().enumerate().filter(|(_, line)| !line.trim().is_empty());
+    let (mut with_flow, mut total_flow) = (0, 0);
+
+    for (valve, (i, line)) in valves.iter().zip(lines).filter(|(valve, _)| valve.flow_rate > 0) {
+        with_flow += 1;
+        total_flow += valve.flow_rate as u32;
+
+        let expected = if with_flow > MAX_VALVES_WITH_FLOW {
+            format!("at most {} valves with flow", MAX_VALVES_WITH_FLOW)
+        } else if total_flow > MAX_TOTAL_FLOW {
+            format!("flow rates adding up to at most {}", MAX_TOTAL_FLOW)
+        } else {
+            continue;
+        };
+        let line = line.trim_end();
+        let column = line.find("rate=").map_or(1, |offset| line[..offset].chars().count() + 1);
+        let found = format!("rate={}", valve.flow_rate);
+        return Err(ParseError { text: line.to_string(), ..ParseError::new(column, expected, found).at_line(i + 1) });
     }
+
+    Ok(valves)
 }
-impl PartialEq for VolcanoState {
-    fn eq(&self, other: &Self) -> bool {
-        // self.open_valves == other.open_valves
-        self.current_positon == other.current_positon        
-        && self.current_flow == other.current_flow
-        && self.pressure_released == other.pressure_released
-        && self.time_left == other.time_left
-    }
+
+/// Where one actor stands, which valves are open and how much time is left.
+/// Positions are indices into [`ValveGraph`], open valves are a bitmask over
+/// the valves with flow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct VolcanoState {
+    open_valves: u64,
+    current_positon: usize,
+    time_left: u8
+}
+
+/// The valves worth opening plus the start, with the walking distance
+/// between every pair of them. Zero-flow valves only matter as corridors,
+/// so they are folded into the distances. [`parse_valves`] makes sure the
+/// valves with flow fit into the open valve mask.
+#[derive(Debug)]
+struct ValveGraph {
+    names: Vec<(char,char)>,
+    flow_rates: Vec<u16>,
+    distances: Vec<Vec<u8>>
 }
-// impl Eq for VolcanoState {}
+impl ValveGraph {
+    fn new(valves: &[Valve]) -> Self {
+        let valves_with_flow = valves
+        .iter()
+        .filter(|v| v.flow_rate > 0)
+        .collect::<Vec<&Valve>>();
+
+        let mut names = valves_with_flow.iter().map(|v| v.name).collect::<Vec<(char,char)>>();
+        let flow_rates = valves_with_flow.iter().map(|v| v.flow_rate).collect::<Vec<u16>>();
+        names.push(('A','A'));
+
+        let distances = names
+        .iter()
+        .map(|from| {
+            let from_here = distances_from(valves, from);
+            names.iter().map(|to| *from_here.get(to).unwrap_or(&u8::MAX)).collect()
+        })
+        .collect();
 
-impl VolcanoState {
-    fn new() -> Self {
         Self {
-            open_valves: BTreeSet::new(),
-            current_positon: ('A','A'),
-            pressure_released: 0,
-            time_left: MAX_TIME_ELAPSED,
-            current_flow: 0,
+            names,
+            flow_rates,
+            distances
         }
     }
 
-    fn pressure_tick(&mut self){
-        self.pressure_released += self.current_flow;
+    fn index_of(&self, name: &(char,char)) -> Option<usize> {
+        self.names.iter().position(|n| n == name)
     }
 
-    fn move_to(&self, valve_to_move_to: &(char, char), graph: &VolcanoStateGraph) -> Self {
-        let mut s = self.clone();
-        s.current_flow = self.get_total_flow_rate(graph);        
-        s.pressure_tick();
-
-        let all_valves_opened = graph.valves_with_flow
-        .iter()
-        .fold(true, |acc, v|{
-            if !acc {
-                false
-            } else {
-                self.open_valves.contains(&v.name)
-            }
-        });
-
-        let current_positon = if all_valves_opened {
-            self.current_positon.clone()
-        } else {
-            valve_to_move_to.clone()
-        };
-        s.current_positon = current_positon;
-        s.time_left = self.time_left - 1;
-
-        s
+    fn initial_state(&self, time: u8) -> VolcanoState {
+        let start = self.index_of(&('A','A')).expect("AA is always part of the graph");
+        VolcanoState { open_valves: 0, current_positon: start, time_left: time }
     }
 
-    fn open_valve(&self, valve: &(char,char), graph: &VolcanoStateGraph) -> Self {
-        let mut s = self.clone();
-        s.current_flow = self.get_total_flow_rate(graph);
-        s.pressure_tick();        
-        s.open_valves.insert(valve.clone());
-        s.time_left = self.time_left - 1;
+    fn all_valves(&self) -> u64 {
+        (0..self.flow_rates.len()).fold(0, |mask, valve| mask | 1 << valve)
+    }
 
-        s
+    /// States reachable by walking to a closed valve from `allowed` and
+    /// opening it, with the pressure that valve releases until the time
+    /// runs out.
+    fn next_states(&self, state: VolcanoState, allowed: u64) -> impl Iterator<Item = (VolcanoState, u16)> + '_ {
+        (0..self.flow_rates.len())
+        .filter(move |valve| allowed & !state.open_valves & (1 << valve) != 0)
+        .filter_map(move |valve| {
+            let time_left = self.distances[state.current_positon][valve]
+                .checked_add(1)
+                .and_then(|minutes| state.time_left.checked_sub(minutes))
+                .filter(|time_left| *time_left > 0)?;
+
+            let next = VolcanoState {
+                open_valves: state.open_valves | (1 << valve),
+                current_positon: valve,
+                time_left
+            };
+            Some((next, self.flow_rates[valve] * time_left as u16))
+        })
     }
 
-    fn get_total_flow_rate(&self, graph: &VolcanoStateGraph) -> u16{
-        self.open_valves.iter().fold(0, |acc, cur| {
-            acc + graph.get_valve(cur).flow_rate
+    /// Walks the states that open only valves from `allowed`. A step costs
+    /// the pressure the valve it opens didn't release while it was closed,
+    /// which is never negative, so the most pressure is the cheapest path.
+    fn pathfinder(&self, time: u8, allowed: u64) -> Pathfinder<impl FnMut(&VolcanoState) -> Vec<(VolcanoState, u16)> + '_> {
+        Pathfinder::new(move |state: &VolcanoState| {
+            self.next_states(*state, allowed)
+            .map(|(next, released)| (next, self.flow_rates[next.current_positon] * time as u16 - released))
+            .collect::<Vec<(VolcanoState, u16)>>()
         })
     }
 
-}
+    /// Pressure given up on the cheapest way to every reachable state.
+    fn pressure_given_up(&self, time: u8, allowed: u64) -> HashMap<VolcanoState, u16> {
+        self.pathfinder(time, allowed).distances([self.initial_state(time)])
+    }
 
-#[derive(Debug)]
-struct VolcanoStateGraph {
-    valves: Vec<Valve>,
-    visited_states: HashSet<VolcanoState>,
-    to_visit: Vec<VolcanoState>,
-    valves_with_flow: Vec<Valve>
-}
-impl VolcanoStateGraph {
-    fn new(input: Vec<String>) -> Self{
-        let valves = input
-        .iter()
-        .map(|l| {
-            Valve::parse_from_line(l.to_string())
-        })
-        .collect::<Vec<Valve>>();
-        let initial_state = VolcanoState::new();
-        
-        let valves_with_flow = valves
-        .clone()
-        .into_iter()
-        .filter(|v| v.flow_rate > 0)
-        .collect::<Vec<Valve>>();
+    /// What the valves open in `state` release over all of `time`, less
+    /// what reaching it gave up. [`parse_valves`] keeps this in a `u16`.
+    fn released(&self, state: &VolcanoState, given_up: u16, time: u8) -> u16 {
+        let open_flow = (0..self.flow_rates.len())
+        .filter(|valve| state.open_valves & (1 << valve) != 0)
+        .map(|valve| self.flow_rates[valve])
+        .sum::<u16>();
 
-        Self {
-            valves,
-            visited_states: HashSet::new(),
-            to_visit: vec![initial_state],
-            valves_with_flow
-        }
+        open_flow * time as u16 - given_up
     }
 
-    fn get_valve(&self, valve_name: &(char,char)) -> Valve {
-        self.valves.iter().find(|&v| v.name == *valve_name ).unwrap().clone()
+    /// The state to end in for the most pressure, opening only valves from
+    /// `allowed`, and that pressure. Ties go to the state with the most
+    /// time left so the route is the same every run.
+    fn best_end(&self, time: u8, allowed: u64) -> (VolcanoState, u16) {
+        self.pressure_given_up(time, allowed)
+        .into_iter()
+        .map(|(state, given_up)| (state, self.released(&state, given_up, time)))
+        .max_by_key(|(state, released)| (*released, state.time_left, state.open_valves, state.current_positon))
+        .expect("the initial state is always reached")
     }
 
-    fn get_current_valve(&self, state: &VolcanoState) -> Valve {
-        self.get_valve(&state.current_positon)
+    fn max_pressure(&self, time: u8, allowed: u64) -> u16 {
+        self.best_end(time, allowed).1
     }
 
-    fn get_max_pressure(&self) -> u16 {
-        self
-        .visited_states
-        .iter()
-        .fold(0, |acc,vs|{
-            if vs.pressure_released > acc {
-                vs.pressure_released
-            } else {
-                acc
-            }
-        })
+    /// Best pressure for every set of valves a single actor can open in
+    /// `time`, indexed by the open valve mask.
+    fn max_pressure_per_open_set(&self, time: u8) -> Vec<u16> {
+        let mut best = vec![0; 1 << self.flow_rates.len()];
+
+        for (state, given_up) in self.pressure_given_up(time, self.all_valves()) {
+            let mask = state.open_valves as usize;
+            best[mask] = best[mask].max(self.released(&state, given_up, time));
+        }
+
+        best
     }
 
-    fn run(&mut self){
-
-        // let mut i:usize = 0;
-        
-        while let Some(s) = self.to_visit.pop() {
-            self.visited_states.insert(s.clone());
-
-            // if i % 100000 == 0 {
-            //     println!("{}: \tto_visit: {:?}, current_pressure: {}, valves_open: {}, time_left: {}, max_pressure: {}, visited: {}", i , self.to_visit.len(), s.pressure_released, s.open_valves.len(), s.time_left, self.get_max_pressure(), self.visited_states.len());
-            // }
-
-            if s.time_left == 0 { continue; }
-
-            let current_valve = self.get_current_valve(&s);
-            // i += 1;
-
-            self
-            .get_valve(&s.current_positon)
-            .possible_destinations
-            .iter()
-            .for_each(|pd|{
-                let new_states = match (
-                    s.open_valves.contains(&current_valve.name), 
-                    current_valve.flow_rate, 
-                ) {                    
-                    (true, _, )
-                    | (_,0 ) => 
-                        vec![
-                            s.move_to(pd, self)
-                        ],
-                    (false, _) =>
-                        vec![
-                            s.move_to(pd, self),
-                            s.open_valve(&current_valve.name, self)
-                        ]
-                };
-                
-                for new_state in new_states {
-                    if !self.visited_states.contains(&new_state) {
-                        self.to_visit.push(new_state.clone());
-                    }
+    /// Splits the valves between you and the elephant so that together you
+    /// release the most pressure. You never need the same valve, so this is
+    /// the best pair of disjoint sets one actor could open on its own.
+    fn best_split(&self, time: u8) -> (u64, u64) {
+        let per_open_set = self.max_pressure_per_open_set(time);
+        let all_valves = per_open_set.len() - 1;
+
+        // best_within[mask] is the best result using only valves from mask
+        let mut best_within = per_open_set.clone();
+        for mask in 0..=all_valves {
+            for valve in 0..self.flow_rates.len() {
+                if mask & (1 << valve) != 0 {
+                    best_within[mask] = best_within[mask].max(best_within[mask ^ (1 << valve)]);
                 }
-            });
+            }
         }
+
+        let mine = (0..=all_valves)
+        .max_by_key(|mine| per_open_set[*mine] + best_within[all_valves ^ mine])
+        .unwrap_or(0);
+
+        (mine as u64, (all_valves ^ mine) as u64)
     }
 }
 
-pub fn part_01(path: Option<&str>) -> u16{
-    let started = Instant::now();
-    let input = read_lines(path.unwrap_or("assets/input.txt"));
-    let mut graph = VolcanoStateGraph::new(input);
+/// One minute through a tunnel to each valve next to `name`.
+fn tunnels(valves: &[Valve], name: &(char,char)) -> Vec<((char,char), u8)> {
+    match valves.iter().find(|v| v.name == *name) {
+        Some(valve) => valve.possible_destinations.iter().map(|destination| (*destination, 1)).collect(),
+        None => vec![],
+    }
+}
+
+/// Minutes from `from` to every valve it can reach.
+fn distances_from(valves: &[Valve], from: &(char,char)) -> HashMap<(char,char), u8> {
+    Pathfinder::new(|name: &(char,char)| tunnels(valves, name)).distances([*from])
+}
 
-    graph.run();
+pub fn part_01(path: Option<&str>) -> u16{
+    let input = aoc_input::read_file(path.unwrap_or("assets/input.txt")).unwrap_or_else(aoc_input::exit);
+    let valves = parse_valves(&input).unwrap_or_else(aoc_input::exit);
+    let max_pressure = max_pressure(&valves, TIME_ALONE);
 
-    println!("Part 01 - max pressure release: {} ", graph.get_max_pressure());
-    println!("Part 01 - execution time: {:?} ", Instant::now() -  started);
+    println!("Part 01 - max pressure release: {} ", max_pressure);
 
-    graph.get_max_pressure()
+    max_pressure
     
 }
 
-#[cfg(test)]
+pub fn part_02(path: Option<&str>) -> u16{
+    let input = aoc_input::read_file(path.unwrap_or("assets/input.txt")).unwrap_or_else(aoc_input::exit);
+    let valves = parse_valves(&input).unwrap_or_else(aoc_input::exit);
+    let max_pressure = max_pressure_with_elephant(&valves, TIME_WITH_ELEPHANT);
+
+    println!("Part 02 - max pressure release with elephant: {} ", max_pressure);
+
+    max_pressure
+}
+
+/// Prints the winning schedule of both parts, as a table or as JSON.
+pub fn explain_parts(path: Option<&str>, as_json: bool) {
+    let input = aoc_input::read_file(path.unwrap_or("assets/input.txt")).unwrap_or_else(aoc_input::exit);
+    let valves = parse_valves(&input).unwrap_or_else(aoc_input::exit);
+    let part_01 = explain(&valves, TIME_ALONE, false);
+    let part_02 = explain(&valves, TIME_WITH_ELEPHANT, true);
+
+    if as_json {
+        let report = serde_json::json!({ "part_01": explanation_json(&part_01), "part_02": explanation_json(&part_02) });
+        println!("{}", serde_json::to_string_pretty(&report).unwrap());
+    } else {
+        println!("Part 01 - alone for {} minutes\n{}\n", TIME_ALONE, render_explanation(&part_01));
+        println!("Part 02 - with the elephant for {} minutes\n{}", TIME_WITH_ELEPHANT, render_explanation(&part_02));
+    }
+}
 
+pub fn max_pressure(valves: &[Valve], time: u8) -> u16 {
+    let graph = ValveGraph::new(valves);
+
+    graph.max_pressure(time, graph.all_valves())
+}
+
+pub fn max_pressure_with_elephant(valves: &[Valve], time: u8) -> u16 {
+    let graph = ValveGraph::new(valves);
+    let (mine, elephants) = graph.best_split(time);
+
+    [mine, elephants]
+    .iter()
+    .map(|allowed| graph.max_pressure(time, *allowed))
+    .sum()
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Default)]
+pub struct AOC_2022_16 {
+    valves: Vec<Valve>
+}
+impl AOC_2022_16 {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Solution for AOC_2022_16 {
+    type Part1 = u16;
+    type Part2 = u16;
+
+    fn name(&self) -> (usize, usize) {
+        (2022,16)
+    }
+
+    fn parse(&mut self, input: &str) -> Result<(), ParseError> {
+        self.valves = parse_valves(input)?;
+        Ok(())
+    }
+
+    fn part1(&self) -> u16 {
+        max_pressure(&self.valves, TIME_ALONE)
+    }
+
+    fn part2(&self) -> u16 {
+        max_pressure_with_elephant(&self.valves, TIME_WITH_ELEPHANT)
+    }
+}
+
+#[cfg(test)]
 mod test {
     use super::*;
 
+    #[test]
+    fn valve_graph_01(){
+        let input = aoc_input::read_file("assets/input_test_01.txt").unwrap();
+        let graph = ValveGraph::new(&parse_valves(&input).unwrap());
+
+        let (aa, bb, jj) = (graph.index_of(&('A','A')).unwrap(), graph.index_of(&('B','B')).unwrap(), graph.index_of(&('J','J')).unwrap());
+
+        assert_eq!(graph.names.len(), 7);
+        assert_eq!(graph.distances[bb][jj], 3);
+        assert_eq!(graph.distances[aa][jj], 2);
+    }
+
     #[test]
     fn volcano_state_01(){
-        let mut state_01 = VolcanoState{
-            current_positon: ('A','A'),
-            open_valves: BTreeSet::new(),
-            pressure_released: 50,
-            current_flow: 30,
-            time_left: 40,
-        };
-        state_01.open_valves.insert(('B','B'));
-        state_01.open_valves.insert(('A','A'));
-        state_01.open_valves.insert(('C','C'));
-        state_01.open_valves.remove(&('C','C'));
-        
-
-        let mut state_02 = VolcanoState{
-            current_positon: ('A','A'),
-            open_valves: BTreeSet::new(),
-            pressure_released: 50,
-            current_flow: 30,
-            time_left: 40,
-        };
-        state_02.open_valves.insert(('A','A'));
-        state_02.open_valves.insert(('B','B'));
+        let state_01 = VolcanoState{ current_positon: 0, open_valves: 0b011, time_left: 20 };
+        let state_02 = VolcanoState{ current_positon: 0, open_valves: 0b101, time_left: 20 };
 
-        let mut hs:HashSet<VolcanoState> = HashSet::new();
-        hs.insert(state_01.clone());
+        let mut memo:HashMap<VolcanoState, u16> = HashMap::new();
+        memo.insert(state_01, 50);
 
-        assert!(hs.contains(&state_02));
+        assert!(!memo.contains_key(&state_02));
+        assert!(memo.contains_key(&VolcanoState{ open_valves: 0b001 | 0b010, ..state_02 }));
+    }
+
+    #[test]
+    fn parse_from_line_01(){
+        let valve = Valve::parse_from_line("Valve HH has flow rate=22; tunnel leads to valve GG ");
+        assert_eq!(valve, Ok(Valve { name: ('H','H'), flow_rate: 22, possible_destinations: vec![('G','G')] }));
 
+        let err = parse_valves("Valve AA has flow rate=0; tunnels lead to valves DD, II, BB\nValve BB has flow rate=x; tunnels lead to valves CC, AA").unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 24, "x"));
     }
-    
+
+    #[test]
+    fn parse_valves_01(){
+        let valve = |i: usize, rate: u16| format!("Valve {}{} has flow rate={}; tunnel leads to valve AA", (b'A' + i as u8 / 26) as char, (b'A' + i as u8 % 26) as char, rate);
+
+        let many = (1..=MAX_VALVES_WITH_FLOW + 1).map(|i| valve(i, 1)).collect::<Vec<String>>().join("\n");
+        let err = parse_valves(&many).unwrap_err();
+        assert_eq!((err.line, err.column, err.expected.as_str()), (MAX_VALVES_WITH_FLOW + 1, 19, "at most 20 valves with flow"));
+
+        let strong = [valve(1, 200), valve(2, 0), valve(3, 58)].join("\n");
+        let err = parse_valves(&strong).unwrap_err();
+        assert_eq!((err.line, err.found.as_str()), (3, "rate=58"));
+        assert!(parse_valves(&[valve(1, 200), valve(3, 57)].join("\n")).is_ok());
+    }
+
     #[test]
     fn part_01_01(){
         let max_pressure_released = part_01(Some("assets/input_test_01.txt"));        
 
         assert_eq!(max_pressure_released, 1651)
     }
+
+    #[test]
+    fn part_02_01(){
+        let max_pressure_released = part_02(Some("assets/input_test_01.txt"));
+
+        assert_eq!(max_pressure_released, 1707)
+    }
 }
\ No newline at end of file
@@ -1,5 +1,22 @@
-use day_16::part_01;
+use std::{env, process};
+
+use day_16::{explain_parts, part_01, part_02};
+
+static USAGE: &str = "usage: day_16 [--explain [--json]]";
 
 fn main() {
-    part_01(None);
+    let args = env::args().skip(1).collect::<Vec<String>>();
+
+    match args.iter().map(|a| a.as_str()).collect::<Vec<&str>>()[..] {
+        [] => {
+            part_01(None);
+            part_02(None);
+        },
+        ["--explain"] => explain_parts(None, false),
+        ["--explain", "--json"] | ["--json", "--explain"] => explain_parts(None, true),
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    }
 }